@@ -1,15 +1,15 @@
 use anyhow::Result;
 
-use std::{
-    convert::{TryFrom, TryInto},
-    path::Path,
-};
+use std::{convert::TryFrom, path::Path};
 
 use crate::{
     idl::{Idl, IdlConst, IdlEvent, IdlState},
     idl_error_code::IdlErrorCode,
     idl_instruction::{IdlInstruction, IdlInstructions},
     idl_metadata::IdlMetadata,
+    idl_type::{
+        ConversionOptions, ExternalType, TargetPointerWidth, EXTERNAL_TYPES,
+    },
     idl_type_definition::IdlTypeDefinition,
 };
 use shank_macro_impl::{
@@ -20,6 +20,7 @@ use shank_macro_impl::{
     instruction::extract_instruction_enums,
     krate::CrateContext,
     macros::ProgramId,
+    parsed_enum::ParsedEnum,
 };
 
 // -----------------
@@ -32,6 +33,9 @@ pub struct ParseIdlConfig {
     pub detect_custom_struct: DetectCustomTypeConfig,
     pub require_program_address: bool,
     pub program_address_override: Option<String>,
+    pub pointer_width: TargetPointerWidth,
+    pub maps_as_tuple_vecs: bool,
+    pub external_types: Vec<ExternalType>,
 }
 
 impl Default for ParseIdlConfig {
@@ -42,6 +46,9 @@ impl Default for ParseIdlConfig {
             detect_custom_struct: Default::default(),
             require_program_address: true,
             program_address_override: None,
+            pointer_width: Default::default(),
+            maps_as_tuple_vecs: false,
+            external_types: EXTERNAL_TYPES.to_vec(),
         }
     }
 }
@@ -53,6 +60,20 @@ impl ParseIdlConfig {
             ..Self::default()
         }
     }
+
+    fn conversion_options<'a>(
+        &'a self,
+        local_types: &'a [String],
+        local_enums: &'a [(String, Vec<String>)],
+    ) -> ConversionOptions<'a> {
+        ConversionOptions {
+            pointer_width: self.pointer_width,
+            maps_as_tuple_vecs: self.maps_as_tuple_vecs,
+            local_types,
+            local_enums,
+            external_types: &self.external_types,
+        }
+    }
 }
 
 // -----------------
@@ -65,12 +86,15 @@ pub fn parse_file(
     config: &ParseIdlConfig,
 ) -> Result<Option<Idl>> {
     let ctx = CrateContext::parse(filename)?;
+    let local_types = local_types(&ctx);
+    let local_enums = local_enums(&ctx);
+    let opts = config.conversion_options(&local_types, &local_enums);
 
     let constants = constants(&ctx)?;
-    let instructions = instructions(&ctx)?;
+    let instructions = instructions(&ctx, opts)?;
     let state = state(&ctx)?;
-    let accounts = accounts(&ctx)?;
-    let types = types(&ctx, &config.detect_custom_struct)?;
+    let accounts = accounts(&ctx, opts)?;
+    let types = types(&ctx, &config.detect_custom_struct, opts)?;
     let events = events(&ctx)?;
     let errors = errors(&ctx)?;
     let metadata = metadata(
@@ -95,18 +119,46 @@ pub fn parse_file(
     Ok(Some(idl))
 }
 
-fn accounts(ctx: &CrateContext) -> Result<Vec<IdlTypeDefinition>> {
+fn local_types(ctx: &CrateContext) -> Vec<String> {
+    ctx.structs()
+        .map(|x| x.ident.to_string())
+        .chain(ctx.enums().map(|x| x.ident.to_string()))
+        .collect()
+}
+
+/// Collects the fieldless enums of the crate with their variant names ordered by
+/// discriminant.
+fn local_enums(ctx: &CrateContext) -> Vec<(String, Vec<String>)> {
+    ctx.enums()
+        .filter_map(|x| ParsedEnum::try_from(x).ok())
+        .filter(|enm| enm.variants.iter().all(|v| v.fields.is_empty()))
+        .map(|enm| {
+            let mut variants = enm.variants;
+            variants.sort_by_key(|v| v.discriminant);
+            let names = variants.iter().map(|v| v.ident.to_string()).collect();
+            (enm.ident.to_string(), names)
+        })
+        .collect()
+}
+
+fn accounts(
+    ctx: &CrateContext,
+    opts: ConversionOptions,
+) -> Result<Vec<IdlTypeDefinition>> {
     let account_structs = extract_account_structs(ctx.structs())?;
 
     let mut accounts: Vec<IdlTypeDefinition> = Vec::new();
     for strct in account_structs {
-        let idl_def: IdlTypeDefinition = strct.try_into()?;
+        let idl_def = IdlTypeDefinition::from_struct_with(strct, opts)?;
         accounts.push(idl_def);
     }
     Ok(accounts)
 }
 
-fn instructions(ctx: &CrateContext) -> Result<Vec<IdlInstruction>> {
+fn instructions(
+    ctx: &CrateContext,
+    opts: ConversionOptions,
+) -> Result<Vec<IdlInstruction>> {
     let instruction_enums =
         extract_instruction_enums(ctx.enums()).map_err(parse_error_into)?;
 
@@ -116,7 +168,8 @@ fn instructions(ctx: &CrateContext) -> Result<Vec<IdlInstruction>> {
     // TODO(thlorenz): Better way to combine those if we don't do the above.
 
     for ix in instruction_enums {
-        let idl_instructions: IdlInstructions = ix.try_into()?;
+        let idl_instructions =
+            IdlInstructions::from_instruction_with(ix, opts)?;
         for ix in idl_instructions.0 {
             instructions.push(ix);
         }
@@ -138,6 +191,7 @@ fn state(_ctx: &CrateContext) -> Result<Option<IdlState>> {
 fn types(
     ctx: &CrateContext,
     detect_custom_type: &DetectCustomTypeConfig,
+    opts: ConversionOptions,
 ) -> Result<Vec<IdlTypeDefinition>> {
     let custom_structs = ctx
         .structs()
@@ -153,8 +207,12 @@ fn types(
 
     let types = custom_structs
         .into_iter()
-        .map(IdlTypeDefinition::try_from)
-        .chain(custom_enums.into_iter().map(IdlTypeDefinition::try_from))
+        .map(|strct| IdlTypeDefinition::from_struct_with(strct.0, opts))
+        .chain(
+            custom_enums
+                .into_iter()
+                .map(|enm| IdlTypeDefinition::from_enum_with(enm.0, opts)),
+        )
         .collect::<Result<Vec<IdlTypeDefinition>>>()?;
 
     Ok(types)
@@ -1,12 +1,17 @@
-use heck::MixedCase;
-use std::convert::{TryFrom, TryInto};
+use heck::{MixedCase, SnakeCase};
+use std::{convert::TryFrom, str::FromStr};
 
 use serde::{Deserialize, Serialize};
-use shank_macro_impl::parsed_struct::StructField;
-use shank_macro_impl::types::{Composite, TypeKind};
+use shank_macro_impl::parsed_struct::{
+    FieldHint, StructField, StructFieldAttr,
+};
+use shank_macro_impl::types::{Composite, TypeKind, Value};
 
-use crate::idl_type::IdlType;
-use anyhow::{Error, Result};
+use crate::idl_type::{
+    is_account_meta, is_dynamic_json_map, non_max_inner, ConversionOptions,
+    IdlType,
+};
+use anyhow::{anyhow, ensure, Context, Error, Result};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IdlField {
@@ -19,27 +24,124 @@ pub struct IdlField {
     pub docs: Option<Vec<String>>,
 }
 
+// -----------------
+// FieldCase
+// -----------------
+/// The case that field names are converted to in the IDL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FieldCase {
+    /// `foo_bar` becomes `fooBar`
+    #[default]
+    MixedCase,
+    /// `foo_bar` stays `foo_bar` and `fooBar` becomes `foo_bar`
+    SnakeCase,
+    /// The identifier is used untouched, i.e. `fooBar` stays `fooBar`
+    Preserve,
+}
+
+impl FromStr for FieldCase {
+    type Err = Error;
+
+    /// Parses the casing as provided via `#[idl(rename_all = "..")]`.
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "camelCase" => Ok(FieldCase::MixedCase),
+            "snake_case" => Ok(FieldCase::SnakeCase),
+            "preserve" => Ok(FieldCase::Preserve),
+            _ => Err(anyhow!(
+                "Unsupported rename_all case \"{}\", expected \"camelCase\", \"snake_case\" or \"preserve\"",
+                s
+            )),
+        }
+    }
+}
+
+impl FieldCase {
+    pub fn apply(&self, name: &str) -> String {
+        match self {
+            FieldCase::MixedCase => name.to_mixed_case(),
+            FieldCase::SnakeCase => name.to_snake_case(),
+            FieldCase::Preserve => name.to_string(),
+        }
+    }
+}
+
 impl TryFrom<StructField> for IdlField {
     type Error = Error;
 
     fn try_from(field: StructField) -> Result<Self> {
-        let docs = auto_docs(&field.rust_type);
+        IdlField::from_struct_field_with(
+            field,
+            0,
+            FieldCase::default(),
+            ConversionOptions::default(),
+        )
+    }
+}
 
-        let ty: IdlType = if let Some(override_type) = field.type_override() {
-            override_type.clone().try_into()?
+impl IdlField {
+    /// Converts the struct field at `index` of its struct, naming it according to the
+    /// provided case and converting its type with the provided options.
+    pub fn from_struct_field_with(
+        field: StructField,
+        index: usize,
+        case: FieldCase,
+        opts: ConversionOptions,
+    ) -> Result<Self> {
+        // auto docs describe the declared type and thus don't apply to overrides
+        let (ty, auto_docs) = if let Some(idl_type) = field.idl_type_str() {
+            (IdlType::from_str_with(idl_type, opts), None)
+        } else if let Some(override_type) = field.type_override() {
+            (
+                IdlType::try_from_rust_type_with_options(
+                    override_type.clone(),
+                    opts,
+                ),
+                None,
+            )
         } else {
-            field.rust_type.clone().try_into()?
+            (
+                IdlType::try_from_rust_type_with_options(
+                    field.rust_type.clone(),
+                    opts,
+                ),
+                auto_docs(&field.rust_type, opts),
+            )
         };
+        let ty = ty.with_context(|| {
+            format!("Failed to convert field `{}`", field.ident)
+        })?;
+
+        // human docs come first, followed by the ones derived from the type
+        let mut docs = field.docs.clone();
+        docs.extend(auto_docs.unwrap_or_default());
+        if is_discriminator(index, &field.ident.to_string(), &ty) {
+            docs.push("@discriminator".to_string());
+        }
+        docs.extend(hint_docs(&field, &ty, opts)?);
+        let docs = if docs.is_empty() { None } else { Some(docs) };
 
         let attrs = field
             .attrs
             .iter()
+            .filter(|attr| {
+                !matches!(
+                    attr,
+                    StructFieldAttr::Hint(_) | StructFieldAttr::SerdeRename(_)
+                )
+            })
             .map(Into::<String>::into)
             .collect::<Vec<String>>();
         let attrs = if attrs.is_empty() { None } else { Some(attrs) };
 
+        // the serde rename is the name on the wire and thus used as is
+        let name = match field.serde_rename() {
+            Some(rename) => rename.to_string(),
+            None => case.apply(&field.ident.to_string()),
+        };
+
         Ok(Self {
-            name: field.ident.to_string().to_mixed_case(),
+            name,
             ty,
             attrs,
             docs,
@@ -47,13 +149,795 @@ impl TryFrom<StructField> for IdlField {
     }
 }
 
+/// Computes the byte offset of each field of a fixed layout, i.e. of a zero-copy account.
+/// Fails if any field has a variable size since the offsets of the fields following it
+/// cannot be known.
+pub fn compute_offsets(fields: &[IdlField]) -> Result<Vec<(String, usize)>> {
+    let mut offset = 0;
+    let mut offsets = Vec::with_capacity(fields.len());
+    for field in fields {
+        let size = field.ty.fixed_size().ok_or_else(|| {
+            anyhow!(
+                "Field `{}` of type {:?} has a variable size, offsets can only be computed for fixed layouts",
+                field.name,
+                field.ty
+            )
+        })?;
+        offsets.push((field.name.clone(), offset));
+        offset += size;
+    }
+    Ok(offsets)
+}
+
 pub fn auto_docs(
     rust_ty: &shank_macro_impl::types::RustType,
+    opts: ConversionOptions,
 ) -> Option<Vec<String>> {
     match &rust_ty.kind {
         TypeKind::Composite(Composite::Decimal(p), _) => {
             Some(vec![format!("decimals={}", p)])
         }
+        TypeKind::Composite(Composite::HashMap, inners)
+            if inners.len() == 2
+                && is_dynamic_json_map(&inners[0], &inners[1], opts) =>
+        {
+            Some(vec!["@dynamicJson".to_string()])
+        }
+        TypeKind::Value(Value::Custom(name))
+            if non_max_inner(name).is_some() =>
+        {
+            Some(vec!["@maxIsNone".to_string()])
+        }
+        TypeKind::Composite(Composite::Vec, inners)
+            if inners.len() == 1 && is_account_meta(&inners[0]) =>
+        {
+            Some(vec!["@accountMeta".to_string()])
+        }
+        TypeKind::Composite(Composite::Vec | Composite::VecDeque, inners)
+            if inners.len() == 1 =>
+        {
+            match IdlType::try_from_rust_type_with_options(
+                inners[0].clone(),
+                opts,
+            ) {
+                Ok(IdlType::Defined(name)) => {
+                    Some(vec![format!("@elementType={}", name)])
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Name of the field which by convention holds the account discriminator.
+pub const DISCRIMINATOR_FIELD: &str = "discriminator";
+
+/// Detects the leading `discriminator: [u8; 8]` field which identifies the account type.
+pub fn is_discriminator(index: usize, name: &str, ty: &IdlType) -> bool {
+    index == 0 && name == DISCRIMINATOR_FIELD && ty.byte_array_len() == Some(8)
+}
+
+// -----------------
+// Field Hints
+// -----------------
+
+/// Address of the system program which public keys default to via `#[idl_system_program_default]`.
+const SYSTEM_PROGRAM_ID: &str = "11111111111111111111111111111111";
+
+/// Number of bytes of the magic number declared via `#[idl_magic]`.
+const MAGIC_LEN: usize = 4;
+
+/// Renders the `@` docs for all `#[idl_*]` hints of the field, verifying that each hint is
+/// applied to a type it supports.
+/// Field attributes are not kept in declaration order, so the hints are sorted to render
+/// their docs deterministically.
+fn hint_docs(
+    field: &StructField,
+    ty: &IdlType,
+    opts: ConversionOptions,
+) -> Result<Vec<String>> {
+    let mut hints = field
+        .attrs
+        .iter()
+        .filter_map(|attr| match attr {
+            StructFieldAttr::Hint(hint) => Some(hint),
+            _ => None,
+        })
+        .collect::<Vec<&FieldHint>>();
+    hints.sort();
+
+    let mut docs = vec![];
+    for hint in hints {
+        docs.extend(hint_doc(field, hint, ty, opts)?);
+    }
+    Ok(docs)
+}
+
+fn hint_doc(
+    field: &StructField,
+    hint: &FieldHint,
+    ty: &IdlType,
+    opts: ConversionOptions,
+) -> Result<Vec<String>> {
+    let ensure_ty = |attr: &str, expected: &str, supported: bool| {
+        ensure_hint_ty(field, ty, attr, expected, supported)
+    };
+    let docs = match hint {
+        FieldHint::Reserved => {
+            ensure_ty("idl_reserved", "arrays", ty.is_array())?;
+            vec!["@reserved".to_string()]
+        }
+        FieldHint::Encoding(encoding) => {
+            ensure_ty("idl_encoding", "bytes", ty == &IdlType::Bytes)?;
+            vec![format!("@encoding {}", encoding)]
+        }
+        FieldHint::TokenLabels(labels) => {
+            let size = match ty {
+                IdlType::Array(_, size) => *size,
+                _ => {
+                    return Err(hint_ty_error(
+                        field,
+                        ty,
+                        "idl_token_labels",
+                        "arrays",
+                    ))
+                }
+            };
+            ensure!(
+                labels.len() == size,
+                "Field `{}` has {} #[idl_token_labels], but is an array of size {}",
+                field.ident,
+                labels.len(),
+                size
+            );
+            vec![format!("@tokenLabels {}", labels.join(","))]
+        }
+        FieldHint::Stream => {
+            ensure_ty(
+                "idl_stream",
+                "vecs, bytes or strings",
+                matches!(
+                    ty,
+                    IdlType::Vec(_) | IdlType::Bytes | IdlType::String
+                ),
+            )?;
+            vec!["@stream".to_string()]
+        }
+        FieldHint::Owner(owner) => {
+            ensure_ty("idl_owner", "public keys", ty == &IdlType::PublicKey)?;
+            vec![format!("@owner={}", owner)]
+        }
+        FieldHint::FixedString => match ty.byte_array_len() {
+            Some(len) => vec![format!("@fixedString len={}", len)],
+            None => {
+                return Err(hint_ty_error(
+                    field,
+                    ty,
+                    "idl_fixed_string",
+                    "byte arrays",
+                ))
+            }
+        },
+        FieldHint::Utf16 => {
+            ensure_ty(
+                "idl_utf16",
+                "Vec<u16>",
+                ty == &IdlType::Vec(Box::new(IdlType::U16)),
+            )?;
+            vec!["@utf16".to_string()]
+        }
+        FieldHint::Account {
+            signer,
+            writable,
+            init_if_needed,
+        } => {
+            ensure_ty("idl_account", "public keys", ty == &IdlType::PublicKey)?;
+            let mut docs = vec![];
+            if *signer {
+                docs.push("@signer".to_string());
+            }
+            if *writable {
+                docs.push("@writable".to_string());
+            }
+            if *init_if_needed {
+                docs.push("@initIfNeeded".to_string());
+            }
+            docs
+        }
+        FieldHint::Default(value) => {
+            // negative integers only apply to signed fields wide enough to hold them
+            let is_int = value.parse::<i128>().is_ok();
+            if is_int && (ty.is_integer() || value.starts_with('-')) {
+                ensure!(
+                    is_int_literal_of(ty, value),
+                    "Field `{}` has a #[idl_default] of {} which is not a valid {:?}",
+                    field.ident,
+                    value,
+                    ty
+                );
+            }
+            vec![format!("@default={}", value)]
+        }
+        FieldHint::Unique => {
+            ensure_ty(
+                "idl_unique",
+                "vecs or sets",
+                matches!(
+                    ty,
+                    IdlType::Vec(_)
+                        | IdlType::HashSet(_)
+                        | IdlType::BTreeSet(_)
+                ),
+            )?;
+            vec!["@unique".to_string()]
+        }
+        FieldHint::Version => {
+            ensure_ty(
+                "idl_version",
+                "u8 or u16",
+                matches!(ty, IdlType::U8 | IdlType::U16),
+            )?;
+            vec!["@version".to_string()]
+        }
+        FieldHint::Histogram => {
+            ensure_ty(
+                "idl_histogram",
+                "vecs of pairs",
+                match ty {
+                    IdlType::Vec(inner) => {
+                        matches!(inner.as_ref(), IdlType::Tuple(items) if items.len() == 2)
+                    }
+                    _ => false,
+                },
+            )?;
+            vec!["@histogram".to_string()]
+        }
+        FieldHint::OptionalUpdate => {
+            ensure_ty(
+                "idl_optional_update",
+                "options",
+                matches!(ty, IdlType::Option(_)),
+            )?;
+            vec!["@optionalUpdate".to_string()]
+        }
+        FieldHint::Range { min, max } => {
+            ensure_ty("idl_range", "integers", ty.is_integer())?;
+            let mut doc = "@range".to_string();
+            if let Some(min) = min {
+                doc.push_str(&format!(" min={}", min));
+            }
+            if let Some(max) = max {
+                doc.push_str(&format!(" max={}", max));
+            }
+            vec![doc]
+        }
+        FieldHint::Magic(bytes) => {
+            if ty.byte_array_len() != Some(MAGIC_LEN) {
+                return Err(hint_ty_error(
+                    field,
+                    ty,
+                    "idl_magic",
+                    "4 byte arrays",
+                ));
+            }
+            ensure!(
+                bytes.len() == MAGIC_LEN,
+                "Field `{}` has a #[idl_magic] of {} bytes, but is an array of size {}",
+                field.ident,
+                bytes.len(),
+                MAGIC_LEN
+            );
+            let hex = bytes
+                .iter()
+                .map(|b| format!("{:02X}", b))
+                .collect::<String>();
+            vec![format!("@magic {}", hex)]
+        }
+        FieldHint::Membership => {
+            ensure_ty(
+                "idl_membership",
+                "maps with bool values",
+                match ty {
+                    IdlType::HashMap(_, val) | IdlType::BTreeMap(_, val) => {
+                        val.as_ref() == &IdlType::Bool
+                    }
+                    _ => false,
+                },
+            )?;
+            vec!["@membership".to_string()]
+        }
+        FieldHint::Mime(mime) => {
+            ensure_ty("idl_mime", "bytes", ty == &IdlType::Bytes)?;
+            vec![format!("@mime {}", mime)]
+        }
+        FieldHint::Leaf => {
+            ensure_ty(
+                "idl_leaf",
+                "32 byte arrays",
+                ty.byte_array_len() == Some(32),
+            )?;
+            vec!["@leaf".to_string()]
+        }
+        FieldHint::Deprecated(note) => vec![format!("@deprecated {}", note)],
+        FieldHint::LenFrom(len_field) => {
+            ensure_ty(
+                "idl_len_from",
+                "vecs, bytes or strings",
+                matches!(
+                    ty,
+                    IdlType::Vec(_) | IdlType::Bytes | IdlType::String
+                ),
+            )?;
+            let mut docs = vec![format!("@lenFrom={}", len_field)];
+            if ty == &IdlType::Vec(Box::new(IdlType::PublicKey)) {
+                docs.push("@pubkeyVec".to_string());
+            }
+            docs
+        }
+        FieldHint::PubkeyKeys => {
+            ensure_ty(
+                "idl_pubkey_keys",
+                "maps with string keys",
+                match ty {
+                    IdlType::HashMap(key, _) | IdlType::BTreeMap(key, _) => {
+                        key.as_ref() == &IdlType::String
+                    }
+                    _ => false,
+                },
+            )?;
+            vec!["@pubkeyKeys".to_string()]
+        }
+        FieldHint::SystemProgramDefault => {
+            ensure_ty(
+                "idl_system_program_default",
+                "public keys or optional public keys",
+                ty == &IdlType::PublicKey
+                    || ty == &IdlType::Option(Box::new(IdlType::PublicKey)),
+            )?;
+            vec![format!("@defaultsTo={}", SYSTEM_PROGRAM_ID)]
+        }
+        FieldHint::Units(units) => {
+            ensure_ty("idl_units", "integers", ty.is_integer())?;
+            vec![format!("@units {}", units)]
+        }
+        FieldHint::MerkleRoot { depth } => {
+            ensure_ty(
+                "idl_merkle_root",
+                "32 byte arrays",
+                ty.byte_array_len() == Some(32),
+            )?;
+            vec![format!("@merkleRoot depth={}", depth)]
+        }
+        FieldHint::Envelope(envelope) => {
+            ensure_ty("idl_envelope", "bytes", ty == &IdlType::Bytes)?;
+            vec![format!("@envelope {}", envelope)]
+        }
+        FieldHint::Datetime => {
+            ensure_ty(
+                "idl_datetime",
+                "i64 or u64",
+                matches!(ty, IdlType::I64 | IdlType::U64),
+            )?;
+            vec!["@datetime unixSeconds".to_string()]
+        }
+        FieldHint::Sorted => {
+            ensure_ty("idl_sorted", "vecs", matches!(ty, IdlType::Vec(_)))?;
+            vec!["@sorted".to_string()]
+        }
+        FieldHint::Relation { name, account } => {
+            ensure_ty(
+                "idl_relation",
+                "public keys",
+                ty == &IdlType::PublicKey,
+            )?;
+            vec![format!("@relation {}->{}", name, account)]
+        }
+        FieldHint::ByteFlag => {
+            ensure_ty(
+                "idl_byte_flag",
+                "[u8; 1]",
+                ty.byte_array_len() == Some(1),
+            )?;
+            vec!["@byteFlag".to_string()]
+        }
+        FieldHint::Computed => vec!["@computed".to_string()],
+        FieldHint::CompactU16 => {
+            ensure_ty(
+                "idl_compact_u16",
+                "vecs or bytes",
+                matches!(ty, IdlType::Vec(_) | IdlType::Bytes),
+            )?;
+            vec!["@compactU16".to_string()]
+        }
+        FieldHint::Tristate => {
+            ensure_ty(
+                "idl_tristate",
+                "Option<bool>",
+                ty == &IdlType::Option(Box::new(IdlType::Bool)),
+            )?;
+            vec!["@tristate".to_string()]
+        }
+        FieldHint::Pda { program, seeds } => {
+            ensure_ty("idl_pda", "public keys", ty == &IdlType::PublicKey)?;
+            let mut docs = vec![format!("@pda seeds=[{}]", seeds.join(", "))];
+            if let Some(program) = program {
+                docs.push(format!("@pdaProgram={}", program));
+            }
+            docs
+        }
+        FieldHint::Reinterpret { int, endian } => {
+            let len = match ty.byte_array_len() {
+                Some(len) => len,
+                None => {
+                    return Err(hint_ty_error(
+                        field,
+                        ty,
+                        "idl_reinterpret",
+                        "byte arrays",
+                    ))
+                }
+            };
+            let width = int_width(int).ok_or_else(|| {
+                anyhow!(
+                    "Field `{}` has a #[idl_reinterpret] to unknown integer {}",
+                    field.ident,
+                    int
+                )
+            })?;
+            ensure!(
+                width == len,
+                "Field `{}` has a #[idl_reinterpret] to {} of {} bytes, but is an array of size {}",
+                field.ident,
+                int,
+                width,
+                len
+            );
+            vec![format!("@reinterpret {} {}", int, endian)]
+        }
+        FieldHint::RoleTable => {
+            ensure_ty(
+                "idl_role_table",
+                "maps from integers to public keys",
+                match ty {
+                    IdlType::HashMap(key, val)
+                    | IdlType::BTreeMap(key, val) => {
+                        key.is_integer() && val.as_ref() == &IdlType::PublicKey
+                    }
+                    _ => false,
+                },
+            )?;
+            vec!["@roleTable".to_string()]
+        }
+        FieldHint::Sensitive => vec!["@sensitive".to_string()],
+        FieldHint::PdaAuthority { bump } => {
+            ensure_ty(
+                "idl_pda_authority",
+                "public keys or 32 byte arrays",
+                ty == &IdlType::PublicKey || ty.byte_array_len() == Some(32),
+            )?;
+            vec![format!("@pdaAuthority bump={}", bump)]
+        }
+        FieldHint::StringEnum(enm) => {
+            ensure_ty("idl_string_enum", "u8", ty == &IdlType::U8)?;
+            let variants = opts.enum_variants(enm).ok_or_else(|| {
+                anyhow!(
+                    "Field `{}` has a #[idl_string_enum({})], but no fieldless enum `{}` is defined",
+                    field.ident,
+                    enm,
+                    enm
+                )
+            })?;
+            vec![format!("@stringEnum {}", variants.join(","))]
+        }
+        FieldHint::Cid(codec) => {
+            ensure_ty("idl_cid", "bytes", ty == &IdlType::Bytes)?;
+            vec![format!("@cid codec={}", codec)]
+        }
+        FieldHint::Remaining { index } => {
+            ensure_ty(
+                "idl_remaining",
+                "public keys",
+                ty == &IdlType::PublicKey,
+            )?;
+            vec![format!("@remaining index={}", index)]
+        }
+        FieldHint::Proof => {
+            ensure_ty(
+                "idl_proof",
+                "vecs of bytes or 32 byte arrays",
+                match ty {
+                    IdlType::Vec(inner) => {
+                        inner.as_ref() == &IdlType::Bytes
+                            || inner.byte_array_len() == Some(32)
+                    }
+                    _ => false,
+                },
+            )?;
+            vec!["@proof".to_string()]
+        }
+        FieldHint::DecimalString => {
+            ensure_ty(
+                "idl_decimal_string",
+                "u128 or i128",
+                matches!(ty, IdlType::U128 | IdlType::I128),
+            )?;
+            vec!["@decimalString".to_string()]
+        }
+        FieldHint::ProgramId => {
+            ensure_ty(
+                "idl_program_id",
+                "public keys",
+                ty == &IdlType::PublicKey,
+            )?;
+            vec!["@programId".to_string()]
+        }
+        FieldHint::Paginated { page_size } => {
+            ensure_ty("idl_paginated", "vecs", matches!(ty, IdlType::Vec(_)))?;
+            vec![format!("@paginated pageSize={}", page_size)]
+        }
+        FieldHint::Ata { mint, owner } => {
+            ensure_ty(
+                "idl_ata",
+                "public keys or [u8; 32]",
+                ty == &IdlType::PublicKey || ty.byte_array_len() == Some(32),
+            )?;
+            vec![format!("@ata mint={} owner={}", mint, owner)]
+        }
+        FieldHint::NoneDefault(value) => {
+            let inner = match ty {
+                IdlType::Option(inner) if inner.is_integer() => inner.as_ref(),
+                _ => {
+                    return Err(hint_ty_error(
+                        field,
+                        ty,
+                        "idl_none_default",
+                        "options of integers",
+                    ))
+                }
+            };
+            ensure!(
+                is_int_literal_of(inner, value),
+                "Field `{}` has a #[idl_none_default] of {} which is not a valid {:?}",
+                field.ident,
+                value,
+                inner
+            );
+            vec![format!("@noneDefault={}", value)]
+        }
+        FieldHint::Bumps => {
+            ensure_ty("idl_bumps", "Vec<u8>", ty == &IdlType::Bytes)?;
+            vec!["@bumps".to_string()]
+        }
+        FieldHint::MaxEntries(max) => {
+            ensure_ty(
+                "idl_max_entries",
+                "maps or sets",
+                matches!(
+                    ty,
+                    IdlType::HashMap(_, _)
+                        | IdlType::BTreeMap(_, _)
+                        | IdlType::HashSet(_)
+                        | IdlType::BTreeSet(_)
+                ),
+            )?;
+            vec![format!("@maxEntries={}", max)]
+        }
+        FieldHint::AuthorityOf(target) => {
+            ensure_ty(
+                "idl_authority_of",
+                "public keys",
+                ty == &IdlType::PublicKey,
+            )?;
+            vec![format!("@authorityOf {}", target)]
+        }
+        FieldHint::Uuid => {
+            ensure_ty("idl_uuid", "[u8; 16]", ty.byte_array_len() == Some(16))?;
+            vec!["@uuid".to_string()]
+        }
+        FieldHint::TxMessage { version } => {
+            ensure_ty("idl_tx_message", "Vec<u8>", ty == &IdlType::Bytes)?;
+            vec![format!("@txMessage version={}", version)]
+        }
+        FieldHint::Bps => {
+            ensure_ty(
+                "idl_bps",
+                "u16 or u32",
+                matches!(ty, IdlType::U16 | IdlType::U32),
+            )?;
+            vec!["@bps".to_string()]
+        }
+        FieldHint::CloseDestination => {
+            ensure_ty(
+                "idl_close_destination",
+                "public keys",
+                ty == &IdlType::PublicKey,
+            )?;
+            vec!["@closeDestination".to_string()]
+        }
+        FieldHint::Secp256k1 => {
+            ensure_ty(
+                "idl_secp256k1",
+                "[u8; 33]",
+                ty.byte_array_len() == Some(33),
+            )?;
+            vec!["@secp256k1".to_string()]
+        }
+        FieldHint::Queue(mode) => {
+            ensure_ty(
+                "idl_queue",
+                "vecs",
+                matches!(ty, IdlType::Vec(_) | IdlType::Bytes),
+            )?;
+            vec![format!("@queue {}", mode)]
+        }
+        FieldHint::UnlockTime => {
+            ensure_ty("idl_unlock_time", "i64", ty == &IdlType::I64)?;
+            vec!["@unlockTime".to_string()]
+        }
+        FieldHint::EthAddress => {
+            ensure_ty(
+                "idl_eth_address",
+                "[u8; 20]",
+                ty.byte_array_len() == Some(20),
+            )?;
+            vec!["@ethAddress".to_string(), "@encoding hex-0x".to_string()]
+        }
+        FieldHint::VersionedBlob { prefix_bytes } => {
+            ensure_ty("idl_versioned_blob", "Vec<u8>", ty == &IdlType::Bytes)?;
+            vec![format!("@versionedBlob prefixBytes={}", prefix_bytes)]
+        }
+        FieldHint::Mint { decimals_field } => {
+            ensure_ty("idl_mint", "public keys", ty == &IdlType::PublicKey)?;
+            vec![format!("@mint decimalsField={}", decimals_field)]
+        }
+        FieldHint::U8Percent => {
+            ensure_ty("idl_u8_percent", "u8", ty == &IdlType::U8)?;
+            vec!["@u8Percent".to_string()]
+        }
+        FieldHint::Distribution => {
+            ensure_ty(
+                "idl_distribution",
+                "vecs of (Pubkey, _) pairs",
+                match ty {
+                    IdlType::Vec(inner) => match inner.as_ref() {
+                        IdlType::Tuple(items) => {
+                            items.len() == 2 && items[0] == IdlType::PublicKey
+                        }
+                        _ => false,
+                    },
+                    _ => false,
+                },
+            )?;
+            vec!["@distribution".to_string()]
+        }
+        FieldHint::Keypair => {
+            ensure_ty(
+                "idl_keypair",
+                "[u8; 64]",
+                ty.byte_array_len() == Some(64),
+            )?;
+            vec!["@keypair".to_string()]
+        }
+    };
+    Ok(docs)
+}
+
+/// Returns the number of bytes of the integer with the provided name.
+fn int_width(int: &str) -> Option<usize> {
+    match int {
+        "u8" | "i8" => Some(1),
+        "u16" | "i16" => Some(2),
+        "u32" | "i32" => Some(4),
+        "u64" | "i64" => Some(8),
+        "u128" | "i128" => Some(16),
         _ => None,
     }
 }
+
+/// Checks that the rendered literal is a value of the provided integer type.
+fn is_int_literal_of(ty: &IdlType, value: &str) -> bool {
+    match ty {
+        IdlType::U8 => value.parse::<u8>().is_ok(),
+        IdlType::I8 => value.parse::<i8>().is_ok(),
+        IdlType::U16 => value.parse::<u16>().is_ok(),
+        IdlType::I16 => value.parse::<i16>().is_ok(),
+        IdlType::U32 => value.parse::<u32>().is_ok(),
+        IdlType::I32 => value.parse::<i32>().is_ok(),
+        IdlType::U64 => value.parse::<u64>().is_ok(),
+        IdlType::I64 => value.parse::<i64>().is_ok(),
+        IdlType::U128 | IdlType::U256 => value.parse::<u128>().is_ok(),
+        IdlType::I128 => value.parse::<i128>().is_ok(),
+        _ => false,
+    }
+}
+
+fn ensure_hint_ty(
+    field: &StructField,
+    ty: &IdlType,
+    attr: &str,
+    expected: &str,
+    supported: bool,
+) -> Result<()> {
+    if supported {
+        Ok(())
+    } else {
+        Err(hint_ty_error(field, ty, attr, expected))
+    }
+}
+
+fn hint_ty_error(
+    field: &StructField,
+    ty: &IdlType,
+    attr: &str,
+    expected: &str,
+) -> Error {
+    anyhow!(
+        "Field `{}` has the #[{}] attribute which only applies to {}, but its type is {:?}",
+        field.ident,
+        attr,
+        expected,
+        ty
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, ty: IdlType) -> IdlField {
+        IdlField {
+            name: name.to_string(),
+            ty,
+            attrs: None,
+            docs: None,
+        }
+    }
+
+    #[test]
+    fn compute_offsets_of_fixed_fields() {
+        let fields = vec![
+            field("bump", IdlType::U8),
+            field("authority", IdlType::PublicKey),
+            field("amounts", IdlType::Array(Box::new(IdlType::U64), 4)),
+            field("active", IdlType::Bool),
+            field("total", IdlType::U128),
+        ];
+        let offsets = compute_offsets(&fields).expect("Failed to compute");
+        assert_eq!(
+            offsets,
+            vec![
+                ("bump".to_string(), 0),
+                ("authority".to_string(), 1),
+                ("amounts".to_string(), 33),
+                ("active".to_string(), 65),
+                ("total".to_string(), 66),
+            ]
+        );
+    }
+
+    #[test]
+    fn compute_offsets_of_variable_field() {
+        let fields =
+            vec![field("bump", IdlType::U8), field("name", IdlType::String)];
+        let err = compute_offsets(&fields).unwrap_err().to_string();
+        assert!(err.contains("Field `name` of type String has a variable size"));
+    }
+
+    #[test]
+    fn field_case_apply() {
+        for (case, foo_bar, mixed) in [
+            (FieldCase::MixedCase, "fooBar", "fooBar"),
+            (FieldCase::SnakeCase, "foo_bar", "foo_bar"),
+            (FieldCase::Preserve, "foo_bar", "fooBar"),
+        ] {
+            assert_eq!(case.apply("foo_bar"), foo_bar, "{:?}", case);
+            assert_eq!(case.apply("fooBar"), mixed, "{:?}", case);
+        }
+        assert_eq!(
+            FieldCase::from_str("preserve").unwrap(),
+            FieldCase::Preserve
+        );
+        assert!(FieldCase::from_str("PascalCase").is_err());
+    }
+}
@@ -10,7 +10,7 @@ use shank_macro_impl::instruction::{
 
 use crate::{
     idl_field::{auto_docs, IdlField},
-    idl_type::IdlType,
+    idl_type::{ConversionOptions, IdlType},
 };
 
 // -----------------
@@ -23,10 +23,21 @@ impl TryFrom<Instruction> for IdlInstructions {
     type Error = Error;
 
     fn try_from(ix: Instruction) -> Result<Self, Self::Error> {
+        IdlInstructions::from_instruction_with(ix, ConversionOptions::default())
+    }
+}
+
+impl IdlInstructions {
+    /// Converts each variant of the instruction enum, converting the types of their
+    /// args with the provided options.
+    pub fn from_instruction_with(
+        ix: Instruction,
+        opts: ConversionOptions,
+    ) -> Result<Self> {
         let instructions = ix
             .variants
             .into_iter()
-            .map(IdlInstruction::try_from)
+            .map(|variant| IdlInstruction::from_variant_with(variant, opts))
             .collect::<Result<Vec<IdlInstruction>>>()?;
         Ok(Self(instructions))
     }
@@ -54,6 +65,17 @@ impl TryFrom<InstructionVariant> for IdlInstruction {
     type Error = Error;
 
     fn try_from(variant: InstructionVariant) -> Result<Self> {
+        IdlInstruction::from_variant_with(variant, ConversionOptions::default())
+    }
+}
+
+impl IdlInstruction {
+    /// Converts the instruction variant, converting the types of its args with the
+    /// provided options.
+    pub fn from_variant_with(
+        variant: InstructionVariant,
+        opts: ConversionOptions,
+    ) -> Result<Self> {
         let InstructionVariant {
             ident,
             field_tys,
@@ -67,12 +89,15 @@ impl TryFrom<InstructionVariant> for IdlInstruction {
             InstructionVariantFields::Named(args) => {
                 let mut parsed: Vec<IdlField> = vec![];
                 for (field_name, field_ty) in args.iter() {
-                    let ty = IdlType::try_from(field_ty.clone())?;
+                    let ty = IdlType::try_from_rust_type_with_options(
+                        field_ty.clone(),
+                        opts,
+                    )?;
                     parsed.push(IdlField {
                         name: field_name.to_mixed_case(),
                         ty,
                         attrs: None,
-                        docs: auto_docs(field_ty),
+                        docs: auto_docs(field_ty, opts),
                     })
                 }
                 Ok(parsed)
@@ -89,12 +114,15 @@ impl TryFrom<InstructionVariant> for IdlInstruction {
                     } else {
                         format!("arg{}", index).to_string()
                     };
-                    let ty = IdlType::try_from(field_ty.clone())?;
+                    let ty = IdlType::try_from_rust_type_with_options(
+                        field_ty.clone(),
+                        opts,
+                    )?;
                     parsed.push(IdlField {
                         name,
                         ty,
                         attrs: None,
-                        docs: auto_docs(field_ty),
+                        docs: auto_docs(field_ty, opts),
                     })
                 }
                 Ok(parsed)
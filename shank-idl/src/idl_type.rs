@@ -1,19 +1,29 @@
-use std::convert::{TryFrom, TryInto};
+use std::{borrow::Cow, convert::TryFrom, str::FromStr};
 
-use anyhow::{Error, Result};
+use anyhow::{Context, Error, Result};
 
 use serde::{Deserialize, Serialize};
 use shank_macro_impl::types::{
     Composite, Primitive, RustType, TypeKind, Value,
 };
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "camelCase")]
 pub enum IdlType {
     Array(Box<IdlType>, usize),
     Bool,
     Bytes,
+    F32,
+    F64,
     Defined(String),
+    /// A generic type defined in the program with its type arguments, i.e.
+    /// `Wrapper<Inner>`
+    DefinedWithGenerics(String, Vec<IdlType>),
+    /// A type defined by another program, i.e. `spl_token::state::Account`
+    DefinedExternal {
+        program: String,
+        name: String,
+    },
     I128,
     I16,
     I32,
@@ -21,10 +31,13 @@ pub enum IdlType {
     I8,
     Option(Box<IdlType>),
     Tuple(Vec<IdlType>),
+    /// Older IDLs spell this `pubkey` or `publickey`
+    #[serde(alias = "pubkey", alias = "publickey")]
     PublicKey,
     String,
     U128,
     U16,
+    U256,
     U32,
     U64,
     U8,
@@ -35,10 +48,531 @@ pub enum IdlType {
     BTreeSet(Box<IdlType>),
 }
 
+impl IdlType {
+    pub fn is_array(&self) -> bool {
+        matches!(self, IdlType::Array(_, _))
+    }
+
+    pub fn is_integer(&self) -> bool {
+        use IdlType::*;
+        matches!(
+            self,
+            I8 | I16 | I32 | I64 | I128 | U8 | U16 | U32 | U64 | U128 | U256
+        )
+    }
+
+    /// Returns the length if this is a `[u8; N]` array.
+    pub fn byte_array_len(&self) -> Option<usize> {
+        match self {
+            IdlType::Array(inner, len) if inner.as_ref() == &IdlType::U8 => {
+                Some(*len)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the number of bytes this type occupies when Borsh serialized
+    /// if that is the same for all its values.
+    pub fn fixed_size(&self) -> Option<usize> {
+        match self.max_size() {
+            (min, Some(max)) if min == max => Some(min),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if all values of this type occupy the same number of
+    /// bytes when Borsh serialized.
+    /// Defined types are not known here and thus considered variable.
+    pub fn is_fixed_size(&self) -> bool {
+        use IdlType::*;
+        match self {
+            Bool | I8 | U8 | I16 | U16 | I32 | U32 | F32 | I64 | U64 | F64
+            | I128 | U128 | U256 | PublicKey => true,
+            Array(inner, _) => inner.is_fixed_size(),
+            Tuple(inners) => inners.iter().all(IdlType::is_fixed_size),
+            Option(_)
+            | String
+            | Bytes
+            | Vec(_)
+            | HashMap(_, _)
+            | BTreeMap(_, _)
+            | HashSet(_)
+            | BTreeSet(_)
+            | Defined(_)
+            | DefinedWithGenerics(_, _)
+            | DefinedExternal { .. } => false,
+        }
+    }
+
+    /// Returns `true` if values of this type differ in their serialized size.
+    pub fn is_variable_size(&self) -> bool {
+        !self.is_fixed_size()
+    }
+
+    /// Returns the minimum and, if it is bounded, the maximum number of bytes
+    /// this type occupies when Borsh serialized.
+    /// Sizes of defined types are not known here and thus unbounded.
+    pub fn max_size(&self) -> (usize, Option<usize>) {
+        use IdlType::*;
+        match self {
+            Bool | I8 | U8 => (1, Some(1)),
+            I16 | U16 => (2, Some(2)),
+            I32 | U32 | F32 => (4, Some(4)),
+            I64 | U64 | F64 => (8, Some(8)),
+            I128 | U128 => (16, Some(16)),
+            U256 => (32, Some(32)),
+            PublicKey => (32, Some(32)),
+            Array(inner, len) => {
+                let (min, max) = inner.max_size();
+                (min * len, max.map(|max| max * len))
+            }
+            // 1 byte for the Some/None tag
+            Option(inner) => (1, inner.max_size().1.map(|max| max + 1)),
+            Tuple(inners) => inners.iter().map(IdlType::max_size).fold(
+                (0, Some(0)),
+                |(acc_min, acc_max), (min, max)| {
+                    (acc_min + min, acc_max.zip(max).map(|(a, b)| a + b))
+                },
+            ),
+            // 4 bytes for the length prefix
+            String
+            | Bytes
+            | Vec(_)
+            | HashMap(_, _)
+            | BTreeMap(_, _)
+            | HashSet(_)
+            | BTreeSet(_) => (4, None),
+            Defined(_) | DefinedWithGenerics(_, _) | DefinedExternal { .. } => {
+                (0, None)
+            }
+        }
+    }
+
+    /// Renders the Rust type that is serialized the same as this type,
+    /// i.e. `Vec<u8>` for [IdlType::Bytes].
+    pub fn to_rust_string(&self) -> String {
+        use IdlType::*;
+        let join = |tys: &[IdlType]| {
+            tys.iter()
+                .map(IdlType::to_rust_string)
+                .collect::<std::vec::Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Array(inner, len) => {
+                format!("[{}; {}]", inner.to_rust_string(), len)
+            }
+            Bool => "bool".to_string(),
+            Bytes => "Vec<u8>".to_string(),
+            Defined(name) => name.clone(),
+            DefinedWithGenerics(name, generics) => {
+                format!("{}<{}>", name, join(generics))
+            }
+            DefinedExternal { name, .. } => name.clone(),
+            F32 => "f32".to_string(),
+            F64 => "f64".to_string(),
+            I128 => "i128".to_string(),
+            I16 => "i16".to_string(),
+            I32 => "i32".to_string(),
+            I64 => "i64".to_string(),
+            I8 => "i8".to_string(),
+            Option(inner) => format!("Option<{}>", inner.to_rust_string()),
+            // `(u8)` would be a parenthesized `u8`
+            Tuple(inners) if inners.len() == 1 => {
+                format!("({},)", inners[0].to_rust_string())
+            }
+            Tuple(inners) => format!("({})", join(inners)),
+            PublicKey => "Pubkey".to_string(),
+            String => "String".to_string(),
+            U128 => "u128".to_string(),
+            U16 => "u16".to_string(),
+            U256 => "Uint<256, 4>".to_string(),
+            U32 => "u32".to_string(),
+            U64 => "u64".to_string(),
+            U8 => "u8".to_string(),
+            Vec(inner) => format!("Vec<{}>", inner.to_rust_string()),
+            HashMap(key, val) => format!(
+                "HashMap<{}, {}>",
+                key.to_rust_string(),
+                val.to_rust_string()
+            ),
+            BTreeMap(key, val) => format!(
+                "BTreeMap<{}, {}>",
+                key.to_rust_string(),
+                val.to_rust_string()
+            ),
+            HashSet(inner) => format!("HashSet<{}>", inner.to_rust_string()),
+            BTreeSet(inner) => format!("BTreeSet<{}>", inner.to_rust_string()),
+        }
+    }
+
+    /// Accumulates the names of all defined types this type references,
+    /// including nested ones, in the order they are first encountered.
+    /// Types defined by other programs are not included.
+    pub fn collect_defined(&self, out: &mut Vec<String>) {
+        use IdlType::*;
+        match self {
+            Defined(name) => {
+                if !out.contains(name) {
+                    out.push(name.clone());
+                }
+            }
+            DefinedWithGenerics(name, generics) => {
+                if !out.contains(name) {
+                    out.push(name.clone());
+                }
+                for generic in generics {
+                    generic.collect_defined(out);
+                }
+            }
+            Array(inner, _)
+            | Option(inner)
+            | Vec(inner)
+            | HashSet(inner)
+            | BTreeSet(inner) => inner.collect_defined(out),
+            HashMap(key, val) | BTreeMap(key, val) => {
+                key.collect_defined(out);
+                val.collect_defined(out);
+            }
+            Tuple(inners) => {
+                for inner in inners {
+                    inner.collect_defined(out);
+                }
+            }
+            Bool
+            | Bytes
+            | F32
+            | F64
+            | I128
+            | I16
+            | I32
+            | I64
+            | I8
+            | PublicKey
+            | String
+            | U128
+            | U16
+            | U256
+            | U32
+            | U64
+            | U8
+            | DefinedExternal { .. } => {}
+        }
+    }
+}
+
+// -----------------
+// TargetPointerWidth
+// -----------------
+/// The pointer width of the target that `usize` and `isize` are sized for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TargetPointerWidth {
+    /// i.e. 32-bit WASM clients
+    Bits32,
+    /// ebpf is 64-bit architecture
+    #[default]
+    Bits64,
+}
+
+// -----------------
+// ConversionOptions
+// -----------------
+/// Options controlling how Rust types are converted to IDL types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConversionOptions<'a> {
+    /// The pointer width that `usize` and `isize` are sized for.
+    pub pointer_width: TargetPointerWidth,
+    /// Convert `HashMap<K, V>` and `BTreeMap<K, V>` to `Vec<(K, V)>` for
+    /// clients that can't decode map types or rely on their ordering.
+    pub maps_as_tuple_vecs: bool,
+    /// Names of the types defined in the crate which take precedence over
+    /// well-known types of the same name, i.e. a user `struct Value`.
+    pub local_types: &'a [String],
+    /// Fieldless enums defined in the crate with their variant names in
+    /// discriminant order, i.e. for `#[idl_string_enum(Status)]`.
+    pub local_enums: &'a [(String, Vec<String>)],
+    /// Types defined by other programs which are referenced as external types,
+    /// defaults to the [EXTERNAL_TYPES].
+    pub external_types: &'a [ExternalType],
+}
+
+impl Default for ConversionOptions<'_> {
+    fn default() -> Self {
+        Self {
+            pointer_width: Default::default(),
+            maps_as_tuple_vecs: false,
+            local_types: &[],
+            local_enums: &[],
+            external_types: EXTERNAL_TYPES,
+        }
+    }
+}
+
+impl ConversionOptions<'_> {
+    pub fn is_local_type(&self, name: &str) -> bool {
+        self.local_types.iter().any(|ty| ty == name)
+    }
+
+    pub fn enum_variants(&self, name: &str) -> Option<&[String]> {
+        self.local_enums
+            .iter()
+            .find(|(enm, _)| enm == name)
+            .map(|(_, variants)| variants.as_slice())
+    }
+}
+
 impl TryFrom<RustType> for IdlType {
     type Error = Error;
 
     fn try_from(rust_ty: RustType) -> Result<Self> {
+        IdlType::try_from_rust_type_with(rust_ty, TargetPointerWidth::default())
+    }
+}
+
+impl FromStr for IdlType {
+    type Err = Error;
+
+    /// Parses the canonical IDL spelling of a type, i.e. `vec<publicKey>` or
+    /// `array<u8, 32>` where any other identifier is a defined type.
+    /// Spellings of Rust types, i.e. `Vec<Pubkey>` or `[u8; 32]`, are
+    /// recognized by their leading uppercase letter or bracket and converted
+    /// like declared Rust types.
+    fn from_str(s: &str) -> Result<Self> {
+        IdlType::from_str_with(s, ConversionOptions::default())
+    }
+}
+
+impl IdlType {
+    /// Parses the type like [IdlType::from_str], converting it with the provided options.
+    pub fn from_str_with(s: &str, opts: ConversionOptions) -> Result<Self> {
+        let s = s.trim();
+        let is_rust_spelling = s
+            .starts_with(|c: char| c.is_ascii_uppercase() || "[(&".contains(c));
+        if is_rust_spelling {
+            anyhow::ensure!(
+                !s.split_whitespace().collect::<String>().contains("<>"),
+                "Invalid IDL type \"{}\": empty type arguments",
+                s
+            );
+            let rust_ty = RustType::try_from(s).map_err(|err| {
+                anyhow::anyhow!("Invalid IDL type \"{}\": {}", s, err)
+            })?;
+            ensure_rust_spelling(s, &rust_ty)?;
+            IdlType::try_from_rust_type_with_options(rust_ty, opts)
+                .with_context(|| format!("Invalid IDL type \"{}\"", s))
+        } else {
+            parse_canonical(s, opts)
+        }
+    }
+}
+
+/// Rejects canonical IDL names inside a Rust spelling, i.e. `string` in
+/// `HashMap<string, u64>`, which would otherwise be taken for defined types.
+fn ensure_rust_spelling(s: &str, rust_ty: &RustType) -> Result<()> {
+    let name = match &rust_ty.kind {
+        TypeKind::Value(Value::Custom(name)) => Some(custom_type_name(name)),
+        TypeKind::Composite(Composite::Custom(name), _) => {
+            Some(custom_type_name(name))
+        }
+        _ => None,
+    };
+    if let Some(name) = name {
+        anyhow::ensure!(
+            !name.starts_with(|c: char| c.is_ascii_lowercase()),
+            "Invalid IDL type \"{}\": `{}` is not a Rust type, Rust and IDL spellings cannot be mixed",
+            s,
+            name
+        );
+    }
+    if let TypeKind::Composite(_, inners) = &rust_ty.kind {
+        for inner in inners {
+            ensure_rust_spelling(s, inner)?;
+        }
+    }
+    Ok(())
+}
+
+fn parse_canonical(s: &str, opts: ConversionOptions) -> Result<IdlType> {
+    let s = s.trim();
+    let open = match s.find('<') {
+        Some(open) => open,
+        None => {
+            anyhow::ensure!(
+                !s.contains('>'),
+                "Invalid IDL type \"{}\": unbalanced angle brackets",
+                s
+            );
+            return parse_canonical_name(s, opts);
+        }
+    };
+    anyhow::ensure!(
+        s.ends_with('>'),
+        "Invalid IDL type \"{}\": unbalanced angle brackets",
+        s
+    );
+    let name = s[..open].trim();
+    let args = split_type_args(&s[open + 1..s.len() - 1]).ok_or_else(|| {
+        anyhow::anyhow!("Invalid IDL type \"{}\": unbalanced angle brackets", s)
+    })?;
+    let expect_args = |len: usize| {
+        anyhow::ensure!(
+            args.len() == len,
+            "Invalid IDL type \"{}\": {} expects {} type arguments, but got {}",
+            s,
+            name,
+            len,
+            args.len()
+        );
+        Ok(())
+    };
+    let ty = |idx: usize| parse_canonical(args[idx], opts).map(Box::new);
+    match name {
+        "vec" => {
+            expect_args(1)?;
+            Ok(IdlType::Vec(ty(0)?))
+        }
+        "option" => {
+            expect_args(1)?;
+            Ok(IdlType::Option(ty(0)?))
+        }
+        "hashSet" => {
+            expect_args(1)?;
+            Ok(IdlType::HashSet(ty(0)?))
+        }
+        "bTreeSet" => {
+            expect_args(1)?;
+            Ok(IdlType::BTreeSet(ty(0)?))
+        }
+        "hashMap" | "bTreeMap" if opts.maps_as_tuple_vecs => {
+            expect_args(2)?;
+            Ok(map_as_tuple_vec(*ty(0)?, *ty(1)?))
+        }
+        "hashMap" => {
+            expect_args(2)?;
+            Ok(IdlType::HashMap(ty(0)?, ty(1)?))
+        }
+        "bTreeMap" => {
+            expect_args(2)?;
+            Ok(IdlType::BTreeMap(ty(0)?, ty(1)?))
+        }
+        "tuple" => Ok(IdlType::Tuple(
+            args.iter()
+                .map(|arg| parse_canonical(arg, opts))
+                .collect::<Result<Vec<_>>>()?,
+        )),
+        "array" => {
+            anyhow::ensure!(
+                args.len() == 2,
+                "Invalid IDL type \"{}\": array expects an element type and length, i.e. array<u8, 32>",
+                s
+            );
+            let len = args[1].trim().parse::<usize>().map_err(|_| {
+                anyhow::anyhow!(
+                    "Invalid IDL type \"{}\": array length \"{}\" is not a number",
+                    s,
+                    args[1].trim()
+                )
+            })?;
+            Ok(IdlType::Array(ty(0)?, len))
+        }
+        _ => anyhow::bail!(
+            "Invalid IDL type \"{}\": unknown type with arguments \"{}\"",
+            s,
+            name
+        ),
+    }
+}
+
+fn parse_canonical_name(
+    name: &str,
+    opts: ConversionOptions,
+) -> Result<IdlType> {
+    let ty = match name {
+        "bool" => IdlType::Bool,
+        "bytes" => IdlType::Bytes,
+        "f32" => IdlType::F32,
+        "f64" => IdlType::F64,
+        "i8" => IdlType::I8,
+        "i16" => IdlType::I16,
+        "i32" => IdlType::I32,
+        "i64" => IdlType::I64,
+        "i128" => IdlType::I128,
+        "u8" => IdlType::U8,
+        "u16" => IdlType::U16,
+        "u32" => IdlType::U32,
+        "u64" => IdlType::U64,
+        "u128" => IdlType::U128,
+        "u256" => IdlType::U256,
+        "publicKey" => IdlType::PublicKey,
+        "string" => IdlType::String,
+        // Rust primitives without canonical spelling, i.e. usize
+        "usize" | "isize" | "char" => {
+            let rust_ty = RustType::try_from(name).map_err(|err| {
+                anyhow::anyhow!("Invalid IDL type \"{}\": {}", name, err)
+            })?;
+            IdlType::try_from_rust_type_with_options(rust_ty, opts)?
+        }
+        _ => {
+            anyhow::ensure!(
+                !name.is_empty()
+                    && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+                    && !name.starts_with(|c: char| c.is_ascii_digit()),
+                "Invalid IDL type \"{}\"",
+                name
+            );
+            IdlType::Defined(name.to_string())
+        }
+    };
+    Ok(ty)
+}
+
+/// Splits the comma separated type arguments ignoring commas of nested
+/// arguments or returns `None` if the angle brackets are unbalanced.
+fn split_type_args(args: &str) -> Option<Vec<&str>> {
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut split = vec![];
+    for (idx, c) in args.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                split.push(&args[start..idx]);
+                start = idx + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 {
+        return None;
+    }
+    split.push(&args[start..]);
+    Some(split)
+}
+
+impl IdlType {
+    /// Converts the rust type, sizing `usize` and `isize` for the provided pointer width.
+    pub fn try_from_rust_type_with(
+        rust_ty: RustType,
+        width: TargetPointerWidth,
+    ) -> Result<Self> {
+        IdlType::try_from_rust_type_with_options(
+            rust_ty,
+            ConversionOptions {
+                pointer_width: width,
+                ..ConversionOptions::default()
+            },
+        )
+    }
+
+    /// Converts the rust type according to the provided options.
+    pub fn try_from_rust_type_with_options(
+        rust_ty: RustType,
+        opts: ConversionOptions,
+    ) -> Result<Self> {
+        let convert =
+            |ty: RustType| IdlType::try_from_rust_type_with_options(ty, opts);
+        let width = opts.pointer_width;
         let idl_ty = match rust_ty.kind {
             TypeKind::Primitive(prim) => match prim {
                 Primitive::U8 => IdlType::U8,
@@ -51,38 +585,62 @@ impl TryFrom<RustType> for IdlType {
                 Primitive::U64 => IdlType::U64,
                 Primitive::U128 => IdlType::U128,
                 Primitive::I128 => IdlType::I128,
-                // ebpf is 64-bit architecture
-                Primitive::USize => IdlType::U64,
+                Primitive::USize => match width {
+                    TargetPointerWidth::Bits32 => IdlType::U32,
+                    TargetPointerWidth::Bits64 => IdlType::U64,
+                },
+                Primitive::ISize => match width {
+                    TargetPointerWidth::Bits32 => IdlType::I32,
+                    TargetPointerWidth::Bits64 => IdlType::I64,
+                },
+                Primitive::F32 => IdlType::F32,
+                Primitive::F64 => IdlType::F64,
+                // a char is a 4 byte unicode scalar value
+                Primitive::Char => IdlType::U32,
                 Primitive::Bool => IdlType::Bool,
             },
             TypeKind::Value(val) => match val {
                 Value::CString | Value::String | Value::Str => IdlType::String,
                 Value::Custom(name) => {
-                    if name == "Pubkey" {
+                    if let Some(external) = external_type(&name, opts) {
+                        IdlType::DefinedExternal {
+                            program: external.program.to_string(),
+                            name: external.name.to_string(),
+                        }
+                    } else if let Some(inner) = non_max_inner(&name) {
+                        inner
+                    } else if let Some(inner) = non_zero_inner(&name) {
+                        inner
+                    } else if let Some(aliased) = type_alias(&name, opts) {
+                        aliased
+                    } else if custom_type_name(&name) == "Pubkey" {
                         IdlType::PublicKey
                     } else {
-                        IdlType::Defined(name)
+                        IdlType::Defined(custom_type_name(&name).to_string())
                     }
                 }
             },
             TypeKind::Composite(kind, inners) => match kind {
-                Composite::Vec => match inners.first().cloned() {
-                    Some(inner) => {
-                        let inner_idl: IdlType = inner.try_into()?;
-                        if inner_idl == IdlType::U8 {
-                            // Vec<u8>
-                            IdlType::Bytes
-                        } else {
-                            IdlType::Vec(Box::new(inner_idl))
+                // VecDeque<T> is serialized the same as Vec<T>
+                Composite::Vec | Composite::VecDeque => {
+                    match inners.first().cloned() {
+                        Some(inner) => {
+                            let inner_idl = convert(inner)?;
+                            if inner_idl == IdlType::U8 {
+                                // Vec<u8> or VecDeque<u8>
+                                IdlType::Bytes
+                            } else {
+                                IdlType::Vec(Box::new(inner_idl))
+                            }
+                        }
+                        None => {
+                            anyhow::bail!("Rust Vec Composite needs inner type")
                         }
                     }
-                    None => {
-                        anyhow::bail!("Rust Vec Composite needs inner type")
-                    }
-                },
+                }
                 Composite::Array(size) => match inners.first().cloned() {
                     Some(inner) => {
-                        let inner_idl: IdlType = inner.try_into()?;
+                        let inner_idl = convert(inner)?;
                         IdlType::Array(Box::new(inner_idl), size)
                     }
                     None => {
@@ -92,7 +650,7 @@ impl TryFrom<RustType> for IdlType {
 
                 Composite::Option => match inners.first().cloned() {
                     Some(inner) => {
-                        let inner_idl: IdlType = inner.try_into()?;
+                        let inner_idl = convert(inner)?;
                         IdlType::Option(Box::new(inner_idl))
                     }
                     None => {
@@ -100,23 +658,34 @@ impl TryFrom<RustType> for IdlType {
                     }
                 },
                 Composite::Tuple => {
-                    if inners.len() < 2 {
-                        anyhow::bail!("Rust Tuple Composite needs at least two inner types");
+                    // single element tuples may be generated by macros
+                    if inners.is_empty() {
+                        anyhow::bail!("Rust Tuple Composite needs at least one inner type");
                     } else {
                         let idl_types: Result<Vec<IdlType>> =
-                            inners.into_iter().map(IdlType::try_from).collect();
+                            inners.into_iter().map(convert).collect();
                         IdlType::Tuple(idl_types?)
                     }
                 }
                 Composite::HashMap => {
                     match (inners.first().cloned(), inners.get(1).cloned()) {
                         (Some(inner1), Some(inner2)) => {
-                            let inner1_idl: IdlType = inner1.try_into()?;
-                            let inner2_idl: IdlType = inner2.try_into()?;
-                            IdlType::HashMap(
-                                Box::new(inner1_idl),
-                                Box::new(inner2_idl),
-                            )
+                            let inner2_idl: IdlType = if is_dynamic_json_map(
+                                &inner1, &inner2, opts,
+                            ) {
+                                IdlType::Defined(DYNAMIC_JSON_TY.to_string())
+                            } else {
+                                convert(inner2)?
+                            };
+                            let inner1_idl = convert(inner1)?;
+                            if opts.maps_as_tuple_vecs {
+                                map_as_tuple_vec(inner1_idl, inner2_idl)
+                            } else {
+                                IdlType::HashMap(
+                                    Box::new(inner1_idl),
+                                    Box::new(inner2_idl),
+                                )
+                            }
                         }
                         _ => {
                             anyhow::bail!(
@@ -128,12 +697,16 @@ impl TryFrom<RustType> for IdlType {
                 Composite::BTreeMap => {
                     match (inners.first().cloned(), inners.get(1).cloned()) {
                         (Some(inner1), Some(inner2)) => {
-                            let inner1_idl: IdlType = inner1.try_into()?;
-                            let inner2_idl: IdlType = inner2.try_into()?;
-                            IdlType::BTreeMap(
-                                Box::new(inner1_idl),
-                                Box::new(inner2_idl),
-                            )
+                            let inner1_idl = convert(inner1)?;
+                            let inner2_idl = convert(inner2)?;
+                            if opts.maps_as_tuple_vecs {
+                                map_as_tuple_vec(inner1_idl, inner2_idl)
+                            } else {
+                                IdlType::BTreeMap(
+                                    Box::new(inner1_idl),
+                                    Box::new(inner2_idl),
+                                )
+                            }
                         }
                         _ => {
                             anyhow::bail!(
@@ -144,7 +717,7 @@ impl TryFrom<RustType> for IdlType {
                 }
                 Composite::HashSet => match inners.first().cloned() {
                     Some(inner) => {
-                        let inner_idl: IdlType = inner.try_into()?;
+                        let inner_idl = convert(inner)?;
                         IdlType::HashSet(Box::new(inner_idl))
                     }
                     _ => {
@@ -155,7 +728,7 @@ impl TryFrom<RustType> for IdlType {
                 },
                 Composite::BTreeSet => match inners.first().cloned() {
                     Some(inner) => {
-                        let inner_idl: IdlType = inner.try_into()?;
+                        let inner_idl = convert(inner)?;
                         IdlType::BTreeSet(Box::new(inner_idl))
                     }
                     _ => {
@@ -169,7 +742,7 @@ impl TryFrom<RustType> for IdlType {
                     // We only care about the inner type (second generic parameter)
                     if inners.len() == 1 {
                         let inner_type = inners[0].clone();
-                        inner_type.try_into()?
+                        convert(inner_type)?
                     } else {
                         anyhow::bail!(
                             "Decimal composite needs one type parameter, got {}",
@@ -177,10 +750,30 @@ impl TryFrom<RustType> for IdlType {
                         )
                     }
                 }
-                Composite::Custom(_) => {
-                    anyhow::bail!(
-                        "Rust Custom Composite IDL type not yet supported"
-                    )
+                Composite::Uint(bits) => match bits {
+                    256 => IdlType::U256,
+                    _ => anyhow::bail!(
+                        "Uint with {} bits is not supported, only 256 bits are",
+                        bits
+                    ),
+                },
+                // smart pointers and Cow<'_, T> are serialized as T
+                Composite::Custom(name)
+                    if is_transparent_wrapper(&name, opts)
+                        && inners.len() == 1 =>
+                {
+                    convert(inners[0].clone())?
+                }
+                Composite::Custom(name) => {
+                    if inners.is_empty() {
+                        anyhow::bail!(
+                            "Rust Custom Composite {} needs inner types",
+                            name
+                        )
+                    }
+                    let generics: Result<Vec<IdlType>> =
+                        inners.into_iter().map(convert).collect();
+                    IdlType::DefinedWithGenerics(name, generics?)
                 }
             },
             TypeKind::Unit => anyhow::bail!("IDL types cannot be Unit ()"),
@@ -192,9 +785,187 @@ impl TryFrom<RustType> for IdlType {
     }
 }
 
+// -----------------
+// ExternalType
+// -----------------
+/// A type defined by another program, i.e. `spl_token::state::Mint`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalType {
+    /// The full path the type is referenced by
+    pub path: Cow<'static, str>,
+    /// The program defining the type
+    pub program: Cow<'static, str>,
+    /// The name of the type in that program
+    pub name: Cow<'static, str>,
+}
+
+impl ExternalType {
+    pub fn new(
+        path: impl Into<Cow<'static, str>>,
+        program: impl Into<Cow<'static, str>>,
+        name: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            program: program.into(),
+            name: name.into(),
+        }
+    }
+
+    const fn well_known(
+        path: &'static str,
+        program: &'static str,
+        name: &'static str,
+    ) -> Self {
+        Self {
+            path: Cow::Borrowed(path),
+            program: Cow::Borrowed(program),
+            name: Cow::Borrowed(name),
+        }
+    }
+}
+
+/// External types that programs commonly reference which are recognized by default.
+/// Provide further external types via [ConversionOptions::external_types].
+pub const EXTERNAL_TYPES: &[ExternalType] = &[
+    ExternalType::well_known(
+        "spl_token::state::Account",
+        "spl_token",
+        "Account",
+    ),
+    ExternalType::well_known("spl_token::state::Mint", "spl_token", "Mint"),
+    ExternalType::well_known(
+        "spl_token::state::Multisig",
+        "spl_token",
+        "Multisig",
+    ),
+];
+
+/// Looks up a custom type in the external types of the provided options.
+pub fn external_type<'a>(
+    path: &str,
+    opts: ConversionOptions<'a>,
+) -> Option<&'a ExternalType> {
+    let path = path.trim_start_matches("::");
+    opts.external_types
+        .iter()
+        .find(|external| external.path == path)
+}
+
+/// Well-known type aliases of `solana_program`, keyed by their name and
+/// mapped to the type they alias.
+/// Extend this registry to recognize further aliases.
+pub const TYPE_ALIASES: &[(&str, IdlType)] = &[
+    ("Slot", IdlType::U64),
+    ("Epoch", IdlType::U64),
+    ("UnixTimestamp", IdlType::I64),
+];
+
+/// Modules which define the [TYPE_ALIASES].
+const TYPE_ALIAS_MODULES: &[&str] =
+    &["solana_program::clock", "solana_sdk::clock"];
+
+/// Looks up the type a custom type aliases in the [TYPE_ALIASES] registry.
+/// Only bare names which aren't defined in the crate and paths into the
+/// [TYPE_ALIAS_MODULES] are considered, i.e. `my_program::Epoch` is left untouched.
+pub fn type_alias(path: &str, opts: ConversionOptions) -> Option<IdlType> {
+    let name = match path.trim_start_matches("::").rsplit_once("::") {
+        Some((module, name)) if TYPE_ALIAS_MODULES.contains(&module) => name,
+        None if !opts.is_local_type(path) => path,
+        _ => return None,
+    };
+    TYPE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map(|(_, ty)| ty.clone())
+}
+
+/// Returns the name of a custom type without the path it was referenced by,
+/// i.e. `Value` for `serde_json::Value`.
+pub fn custom_type_name(path: &str) -> &str {
+    path.rsplit("::").next().unwrap_or(path)
+}
+
+/// Detects wrappers like `Box<T>` which don't change the serialized layout
+/// of the wrapped type unless the crate defines its own type of that name.
+fn is_transparent_wrapper(path: &str, opts: ConversionOptions) -> bool {
+    let name = custom_type_name(path);
+    matches!(name, "Box" | "Cow" | "Arc" | "Rc") && !opts.is_local_type(name)
+}
+
+/// Represents a map as vec of its key/value pairs.
+fn map_as_tuple_vec(key: IdlType, val: IdlType) -> IdlType {
+    IdlType::Vec(Box::new(IdlType::Tuple(vec![key, val])))
+}
+
+/// Returns the integer wrapped by `NonMaxU64`-style types which use the max
+/// value as sentinel for `None` and are serialized as the plain integer.
+pub fn non_max_inner(path: &str) -> Option<IdlType> {
+    match custom_type_name(path) {
+        "NonMaxU8" => Some(IdlType::U8),
+        "NonMaxU16" => Some(IdlType::U16),
+        "NonMaxU32" => Some(IdlType::U32),
+        "NonMaxU64" => Some(IdlType::U64),
+        "NonMaxU128" => Some(IdlType::U128),
+        _ => None,
+    }
+}
+
+/// Returns the integer wrapped by `std::num::NonZeroU64`-style types which
+/// are serialized as the plain integer.
+pub fn non_zero_inner(path: &str) -> Option<IdlType> {
+    match custom_type_name(path) {
+        "NonZeroU8" => Some(IdlType::U8),
+        "NonZeroU16" => Some(IdlType::U16),
+        "NonZeroU32" => Some(IdlType::U32),
+        "NonZeroU64" => Some(IdlType::U64),
+        "NonZeroU128" => Some(IdlType::U128),
+        "NonZeroI8" => Some(IdlType::I8),
+        "NonZeroI16" => Some(IdlType::I16),
+        "NonZeroI32" => Some(IdlType::I32),
+        "NonZeroI64" => Some(IdlType::I64),
+        "NonZeroI128" => Some(IdlType::I128),
+        _ => None,
+    }
+}
+
+/// Name of the well-known type that dynamic `serde_json::Value`s are exposed as.
+pub const DYNAMIC_JSON_TY: &str = "Json";
+
+/// Detects `HashMap<String, Value>` style dynamic metadata where `Value` is a
+/// `serde_json::Value` which cannot be typed in the IDL.
+pub fn is_dynamic_json_map(
+    key: &RustType,
+    val: &RustType,
+    opts: ConversionOptions,
+) -> bool {
+    key.kind.is_string_like()
+        && matches!(&val.kind, TypeKind::Value(Value::Custom(name)) if is_json_value(name, opts))
+}
+
+/// Matches `serde_json::Value` and a bare `Value` unless the crate defines its
+/// own type of that name.
+fn is_json_value(path: &str, opts: ConversionOptions) -> bool {
+    match path.trim_start_matches("::") {
+        "serde_json::Value" => true,
+        "Value" => !opts.is_local_type("Value"),
+        _ => false,
+    }
+}
+
+/// Name of `solana_program::instruction::AccountMeta` which is well-known to
+/// clients and thus doesn't need to be defined in the IDL.
+pub const ACCOUNT_META_TY: &str = "AccountMeta";
+
+/// Detects `AccountMeta`s which appear in CPI forwarding programs.
+pub fn is_account_meta(rust_ty: &RustType) -> bool {
+    matches!(&rust_ty.kind, TypeKind::Value(Value::Custom(name)) if custom_type_name(name) == ACCOUNT_META_TY)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::convert::TryInto;
 
     #[test]
     fn idl_from_rust_type_primivives() {
@@ -204,6 +975,8 @@ mod tests {
             (Primitive::I128, IdlType::I128),
             (Primitive::Bool, IdlType::Bool),
             (Primitive::USize, IdlType::U64),
+            (Primitive::F32, IdlType::F32),
+            (Primitive::F64, IdlType::F64),
         ] {
             let rust_ty = RustType::owned_primitive("prim", rust_prim);
             let idl_ty: IdlType =
@@ -211,6 +984,57 @@ mod tests {
             assert_eq!(idl_ty, idl_expected);
         }
     }
+    #[test]
+    fn idl_from_rust_type_isize() {
+        let rust_ty = RustType::owned_primitive("off", Primitive::ISize);
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(idl_ty, IdlType::I64);
+    }
+
+    #[test]
+    fn idl_from_rust_type_pointer_width() {
+        for (rust_ty, idl_expected_32, idl_expected_64) in [
+            (
+                RustType::owned_primitive("len", Primitive::USize),
+                IdlType::U32,
+                IdlType::U64,
+            ),
+            (
+                RustType::owned_primitive("off", Primitive::ISize),
+                IdlType::I32,
+                IdlType::I64,
+            ),
+            (
+                RustType::owned_vec_primitive("lens", Primitive::USize),
+                IdlType::Vec(Box::new(IdlType::U32)),
+                IdlType::Vec(Box::new(IdlType::U64)),
+            ),
+            (
+                RustType::owned_option_primitive("len", Primitive::USize),
+                IdlType::Option(Box::new(IdlType::U32)),
+                IdlType::Option(Box::new(IdlType::U64)),
+            ),
+        ] {
+            let idl_ty = IdlType::try_from_rust_type_with(
+                rust_ty.clone(),
+                TargetPointerWidth::Bits32,
+            )
+            .expect("Failed to convert");
+            assert_eq!(idl_ty, idl_expected_32);
+
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(idl_ty, idl_expected_64);
+        }
+    }
+
+    #[test]
+    fn idl_from_rust_type_char() {
+        let rust_ty = RustType::owned_primitive("c", Primitive::Char);
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(idl_ty, IdlType::U32);
+    }
+
     #[test]
     fn idl_from_rust_type_string() {
         let rust_ty = RustType::owned_string("s");
@@ -260,4 +1084,590 @@ mod tests {
         let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
         assert_eq!(idl_ty, IdlType::Option(Box::new(IdlType::I64)));
     }
+
+    #[test]
+    fn idl_from_rust_type_hash_map_string_json_value() {
+        let rust_ty = RustType::owned(
+            "metadata",
+            TypeKind::Composite(
+                Composite::HashMap,
+                vec![
+                    RustType::owned_string("key"),
+                    RustType::owned_custom_value("val", "Value"),
+                ],
+            ),
+        );
+        assert_eq!(
+            crate::idl_field::auto_docs(&rust_ty, ConversionOptions::default()),
+            Some(vec!["@dynamicJson".to_string()])
+        );
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(
+            idl_ty,
+            IdlType::HashMap(
+                Box::new(IdlType::String),
+                Box::new(IdlType::Defined("Json".to_string()))
+            )
+        );
+    }
+
+    #[test]
+    fn idl_from_rust_type_hash_map_string_other_value() {
+        let rust_ty = RustType::owned(
+            "quotes",
+            TypeKind::Composite(
+                Composite::HashMap,
+                vec![
+                    RustType::owned_string("key"),
+                    RustType::owned_custom_value("val", "pricing::Value"),
+                ],
+            ),
+        );
+        assert_eq!(
+            crate::idl_field::auto_docs(&rust_ty, ConversionOptions::default()),
+            None
+        );
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(
+            idl_ty,
+            IdlType::HashMap(
+                Box::new(IdlType::String),
+                Box::new(IdlType::Defined("Value".to_string()))
+            )
+        );
+    }
+
+    #[test]
+    fn idl_from_rust_type_option_array_u8_max_size() {
+        let rust_ty = RustType::owned(
+            "maybe_hash",
+            TypeKind::Composite(
+                Composite::Option,
+                vec![RustType::owned_array_primitive(
+                    "hash",
+                    Primitive::U8,
+                    32,
+                )],
+            ),
+        );
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(
+            idl_ty,
+            IdlType::Option(Box::new(IdlType::Array(
+                Box::new(IdlType::U8),
+                32
+            )))
+        );
+        assert_eq!(idl_ty.max_size(), (1, Some(33)));
+    }
+
+    #[test]
+    fn idl_from_rust_type_vec_account_meta() {
+        let rust_ty = RustType::owned(
+            "metas",
+            TypeKind::Composite(
+                Composite::Vec,
+                vec![RustType::owned_custom_value("meta", "AccountMeta")],
+            ),
+        );
+        assert_eq!(
+            crate::idl_field::auto_docs(&rust_ty, ConversionOptions::default()),
+            Some(vec!["@accountMeta".to_string()])
+        );
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(
+            idl_ty,
+            IdlType::Vec(Box::new(IdlType::Defined("AccountMeta".to_string())))
+        );
+    }
+
+    #[test]
+    fn idl_from_rust_type_spl_token_external() {
+        for (path, name) in [
+            ("spl_token::state::Account", "Account"),
+            ("::spl_token::state::Mint", "Mint"),
+        ] {
+            let rust_ty =
+                RustType::try_from(path).expect("Failed to parse rust type");
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(
+                idl_ty,
+                IdlType::DefinedExternal {
+                    program: "spl_token".to_string(),
+                    name: name.to_string(),
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn idl_from_rust_type_qualified_paths() {
+        for (path, idl_expected) in [
+            ("solana_program::pubkey::Pubkey", IdlType::PublicKey),
+            ("crate::state::Vault", IdlType::Defined("Vault".to_string())),
+            ("std::string::String", IdlType::String),
+        ] {
+            let rust_ty =
+                RustType::try_from(path).expect("Failed to parse rust type");
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(idl_ty, idl_expected);
+        }
+    }
+
+    #[test]
+    fn idl_from_rust_type_ruint_u256() {
+        let rust_ty = RustType::try_from("ruint::Uint<256, 4>")
+            .expect("Failed to parse rust type");
+        assert_eq!(
+            rust_ty.kind,
+            TypeKind::Composite(Composite::Uint(256), vec![])
+        );
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(idl_ty, IdlType::U256);
+        assert_eq!(idl_ty.max_size(), (32, Some(32)));
+
+        let rust_ty = RustType::try_from("Uint<512, 8>")
+            .expect("Failed to parse rust type");
+        let res: Result<IdlType> = rust_ty.try_into();
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn idl_from_rust_type_generic_wrapper_of_defined() {
+        let rust_ty = RustType::try_from("Wrapper<Inner>")
+            .expect("Failed to parse rust type");
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(
+            idl_ty,
+            IdlType::DefinedWithGenerics(
+                "Wrapper".to_string(),
+                vec![IdlType::Defined("Inner".to_string())]
+            )
+        );
+
+        let rust_ty = RustType::try_from("Wrapper<Vec<Inner>>")
+            .expect("Failed to parse rust type");
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(
+            idl_ty,
+            IdlType::DefinedWithGenerics(
+                "Wrapper".to_string(),
+                vec![IdlType::Vec(Box::new(IdlType::Defined(
+                    "Inner".to_string()
+                )))]
+            )
+        );
+    }
+
+    #[test]
+    fn idl_from_rust_type_non_max_u64() {
+        let rust_ty = RustType::try_from("nonmax::NonMaxU64")
+            .expect("Failed to parse rust type");
+        assert_eq!(
+            crate::idl_field::auto_docs(&rust_ty, ConversionOptions::default()),
+            Some(vec!["@maxIsNone".to_string()])
+        );
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(idl_ty, IdlType::U64);
+    }
+
+    #[test]
+    fn idl_from_rust_type_non_zero() {
+        for (ty, idl_expected) in [
+            ("NonZeroU64", IdlType::U64),
+            ("NonZeroI32", IdlType::I32),
+            ("std::num::NonZeroU128", IdlType::U128),
+            ("Option<NonZeroU8>", IdlType::Option(Box::new(IdlType::U8))),
+        ] {
+            let rust_ty =
+                RustType::try_from(ty).expect("Failed to parse rust type");
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(idl_ty, idl_expected);
+        }
+    }
+
+    #[test]
+    fn idl_from_rust_type_tuple_single_element() {
+        let rust_ty = RustType::owned(
+            "single",
+            TypeKind::Composite(
+                Composite::Tuple,
+                vec![RustType::owned_primitive("single", Primitive::U32)],
+            ),
+        );
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(idl_ty, IdlType::Tuple(vec![IdlType::U32]));
+
+        let rust_ty = RustType::owned(
+            "empty",
+            TypeKind::Composite(Composite::Tuple, vec![]),
+        );
+        let res: Result<IdlType> = rust_ty.try_into();
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn idl_from_rust_type_nested_floats() {
+        let rust_ty = RustType::owned(
+            "score",
+            TypeKind::Composite(
+                Composite::Option,
+                vec![RustType::owned_primitive("score", Primitive::F64)],
+            ),
+        );
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(idl_ty, IdlType::Option(Box::new(IdlType::F64)));
+
+        let rust_ty = RustType::owned_vec_primitive("weights", Primitive::F32);
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(idl_ty, IdlType::Vec(Box::new(IdlType::F32)));
+        assert_eq!(serde_json::to_string(&idl_ty).unwrap(), r#"{"vec":"f32"}"#);
+    }
+
+    #[test]
+    fn idl_from_rust_type_box() {
+        for (ty, idl_expected) in [
+            (
+                "Box<SomeStruct>",
+                IdlType::Defined("SomeStruct".to_string()),
+            ),
+            ("Box<Vec<u8>>", IdlType::Bytes),
+        ] {
+            let rust_ty =
+                RustType::try_from(ty).expect("Failed to parse rust type");
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(idl_ty, idl_expected);
+        }
+    }
+
+    #[test]
+    fn idl_from_rust_type_cow() {
+        for (ty, idl_expected) in [
+            ("Cow<'a, str>", IdlType::String),
+            ("Cow<'static, str>", IdlType::String),
+            ("Cow<'a, [u8]>", IdlType::Bytes),
+            ("Cow<'a, [u32]>", IdlType::Vec(Box::new(IdlType::U32))),
+            (
+                "Cow<'a, SomeStruct>",
+                IdlType::Defined("SomeStruct".to_string()),
+            ),
+        ] {
+            let rust_ty =
+                RustType::try_from(ty).expect("Failed to parse rust type");
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(idl_ty, idl_expected);
+        }
+    }
+
+    #[test]
+    fn idl_from_rust_type_vec_deque() {
+        for (ty, idl_expected) in [
+            ("VecDeque<u16>", IdlType::Vec(Box::new(IdlType::U16))),
+            ("VecDeque<u8>", IdlType::Bytes),
+            ("std::collections::VecDeque<u8>", IdlType::Bytes),
+        ] {
+            let rust_ty =
+                RustType::try_from(ty).expect("Failed to parse rust type");
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(idl_ty, idl_expected);
+        }
+    }
+
+    #[test]
+    fn idl_type_collect_defined() {
+        let ty = IdlType::Tuple(vec![
+            IdlType::Option(Box::new(IdlType::Vec(Box::new(
+                IdlType::Defined("Foo".to_string()),
+            )))),
+            IdlType::HashMap(
+                Box::new(IdlType::Defined("Key".to_string())),
+                Box::new(IdlType::Defined("Val".to_string())),
+            ),
+            IdlType::Array(Box::new(IdlType::Defined("Foo".to_string())), 2),
+            IdlType::U64,
+        ]);
+        let mut defined = vec![];
+        ty.collect_defined(&mut defined);
+        assert_eq!(defined, vec!["Foo", "Key", "Val"]);
+    }
+
+    #[test]
+    fn idl_type_fixed_size() {
+        let u64_array = IdlType::Array(Box::new(IdlType::U64), 4);
+        for (ty, expected) in [
+            (u64_array.clone(), Some(32)),
+            (IdlType::PublicKey, Some(32)),
+            (IdlType::Bool, Some(1)),
+            (
+                IdlType::Tuple(vec![IdlType::U8, IdlType::PublicKey]),
+                Some(33),
+            ),
+            (IdlType::Array(Box::new(u64_array), 2), Some(64)),
+            (IdlType::Vec(Box::new(IdlType::U8)), None),
+            (IdlType::Bytes, None),
+            (IdlType::String, None),
+            (IdlType::Option(Box::new(IdlType::U64)), None),
+            (
+                IdlType::HashMap(Box::new(IdlType::U8), Box::new(IdlType::U8)),
+                None,
+            ),
+            (
+                IdlType::Tuple(vec![
+                    IdlType::U8,
+                    IdlType::Array(Box::new(IdlType::String), 2),
+                ]),
+                None,
+            ),
+        ] {
+            assert_eq!(ty.fixed_size(), expected, "{:?}", ty);
+        }
+    }
+
+    #[test]
+    fn idl_type_is_fixed_size() {
+        let boxed = |ty: IdlType| Box::new(ty);
+        for (ty, fixed) in [
+            (IdlType::U64, true),
+            (IdlType::PublicKey, true),
+            (IdlType::Array(boxed(IdlType::U8), 32), true),
+            (IdlType::Array(boxed(IdlType::String), 2), false),
+            (IdlType::Tuple(vec![IdlType::U8, IdlType::Bool]), true),
+            (IdlType::Tuple(vec![IdlType::U8, IdlType::Bytes]), false),
+            (IdlType::Option(boxed(IdlType::U8)), false),
+            (IdlType::String, false),
+            (IdlType::Bytes, false),
+            (IdlType::Vec(boxed(IdlType::U8)), false),
+            (
+                IdlType::HashMap(boxed(IdlType::U8), boxed(IdlType::U8)),
+                false,
+            ),
+            (
+                IdlType::BTreeMap(boxed(IdlType::U8), boxed(IdlType::U8)),
+                false,
+            ),
+            (IdlType::HashSet(boxed(IdlType::U8)), false),
+            (IdlType::BTreeSet(boxed(IdlType::U8)), false),
+        ] {
+            assert_eq!(ty.is_fixed_size(), fixed, "{:?}", ty);
+            assert_eq!(
+                ty.is_fixed_size(),
+                ty.fixed_size().is_some(),
+                "{:?}",
+                ty
+            );
+            assert_eq!(ty.is_variable_size(), !fixed, "{:?}", ty);
+        }
+    }
+
+    #[test]
+    fn idl_type_to_rust_string_round_trip() {
+        for ty in [
+            "u8",
+            "i64",
+            "bool",
+            "String",
+            "Pubkey",
+            "Vec<u8>",
+            "[u8; 5]",
+            "Option<i64>",
+            "Vec<Option<u16>>",
+            "(u8, Pubkey, String)",
+            "(u8,)",
+            "Vec<(Pubkey,)>",
+            "HashMap<String, u64>",
+            "BTreeMap<Pubkey, Vec<u8>>",
+            "HashSet<u32>",
+            "BTreeSet<SomeStruct>",
+            "Wrapper<u64>",
+            "Uint<256, 4>",
+        ] {
+            let rust_ty =
+                RustType::try_from(ty).expect("Failed to parse rust type");
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(idl_ty.to_rust_string(), ty);
+        }
+    }
+
+    #[test]
+    fn idl_type_deserialize_public_key_aliases() {
+        for json in [r#""publicKey""#, r#""pubkey""#, r#""publickey""#] {
+            let idl_ty: IdlType =
+                serde_json::from_str(json).expect("Failed to deserialize");
+            assert_eq!(idl_ty, IdlType::PublicKey);
+        }
+        let idl_ty: IdlType = serde_json::from_str(r#"{"vec":"pubkey"}"#)
+            .expect("Failed to deserialize");
+        assert_eq!(idl_ty, IdlType::Vec(Box::new(IdlType::PublicKey)));
+        assert_eq!(
+            serde_json::to_string(&IdlType::PublicKey).unwrap(),
+            r#""publicKey""#
+        );
+    }
+
+    #[test]
+    fn idl_type_from_str() {
+        assert_eq!(IdlType::from_str("u64").unwrap(), IdlType::U64);
+        assert_eq!(
+            IdlType::from_str("Vec<Pubkey>").unwrap(),
+            IdlType::Vec(Box::new(IdlType::PublicKey))
+        );
+        assert!(IdlType::from_str("Vec<").is_err());
+    }
+
+    #[test]
+    fn idl_type_from_str_rust_spelling_errors() {
+        for (s, err) in [
+            ("Vec<>", "empty type arguments"),
+            ("Box< >", "empty type arguments"),
+            ("Cow<'a>", "Can only convert known types"),
+            ("HashMap<string, u64>", "`string` is not a Rust type"),
+            ("Option<vec<u8>>", "`vec` is not a Rust type"),
+        ] {
+            let actual = format!("{:#}", IdlType::from_str(s).unwrap_err());
+            assert!(actual.contains(err), "{}: {}", s, actual);
+        }
+    }
+
+    #[test]
+    fn idl_type_from_str_canonical() {
+        for (s, expected) in [
+            ("bool", IdlType::Bool),
+            ("publicKey", IdlType::PublicKey),
+            ("string", IdlType::String),
+            ("bytes", IdlType::Bytes),
+            ("usize", IdlType::U64),
+            ("SomeStruct", IdlType::Defined("SomeStruct".to_string())),
+            (
+                "vec<option<u8>>",
+                IdlType::Vec(Box::new(IdlType::Option(Box::new(IdlType::U8)))),
+            ),
+            (
+                "array<publicKey, 32>",
+                IdlType::Array(Box::new(IdlType::PublicKey), 32),
+            ),
+            (
+                "hashMap<string, vec<u64>>",
+                IdlType::HashMap(
+                    Box::new(IdlType::String),
+                    Box::new(IdlType::Vec(Box::new(IdlType::U64))),
+                ),
+            ),
+        ] {
+            assert_eq!(IdlType::from_str(s).unwrap(), expected, "{}", s);
+        }
+    }
+
+    #[test]
+    fn idl_type_from_str_canonical_errors() {
+        for (s, err) in [
+            ("vec<option<u8>", "unbalanced angle brackets"),
+            ("vec<u8>>", "unbalanced angle brackets"),
+            ("array<u8>", "array expects an element type and length"),
+            ("array<u8, n>", "array length \"n\" is not a number"),
+            (
+                "option<u8, u16>",
+                "option expects 1 type arguments, but got 2",
+            ),
+            ("vec<>", "Invalid IDL type \"\""),
+        ] {
+            let actual = IdlType::from_str(s).unwrap_err().to_string();
+            assert!(actual.contains(err), "{}: {}", s, actual);
+        }
+    }
+
+    #[test]
+    fn idl_from_rust_type_maps_as_tuple_vecs() {
+        let opts = ConversionOptions {
+            maps_as_tuple_vecs: true,
+            ..ConversionOptions::default()
+        };
+        for ty in ["HashMap<String, u64>", "BTreeMap<String, u64>"] {
+            let rust_ty =
+                RustType::try_from(ty).expect("Failed to parse rust type");
+            let idl_ty =
+                IdlType::try_from_rust_type_with_options(rust_ty, opts)
+                    .expect("Failed to convert");
+            assert_eq!(
+                idl_ty,
+                IdlType::Vec(Box::new(IdlType::Tuple(vec![
+                    IdlType::String,
+                    IdlType::U64
+                ])))
+            );
+        }
+
+        let rust_ty = RustType::try_from("HashMap<String, u64>")
+            .expect("Failed to parse rust type");
+        let idl_ty: IdlType = rust_ty.try_into().expect("Failed to convert");
+        assert_eq!(
+            idl_ty,
+            IdlType::HashMap(Box::new(IdlType::String), Box::new(IdlType::U64))
+        );
+    }
+
+    #[test]
+    fn idl_from_rust_type_solana_aliases() {
+        for (ty, idl_expected) in [
+            ("Slot", IdlType::U64),
+            ("Epoch", IdlType::U64),
+            ("UnixTimestamp", IdlType::I64),
+            ("solana_program::clock::Slot", IdlType::U64),
+            ("::solana_sdk::clock::Epoch", IdlType::U64),
+            ("staking::Epoch", IdlType::Defined("Epoch".to_string())),
+            (
+                "Option<UnixTimestamp>",
+                IdlType::Option(Box::new(IdlType::I64)),
+            ),
+        ] {
+            let rust_ty =
+                RustType::try_from(ty).expect("Failed to parse rust type");
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(idl_ty, idl_expected);
+        }
+    }
+
+    #[test]
+    fn idl_from_rust_type_arc_rc() {
+        for (ty, idl_expected) in [
+            (
+                "Arc<SomeStruct>",
+                IdlType::Defined("SomeStruct".to_string()),
+            ),
+            ("Rc<u32>", IdlType::U32),
+            ("Arc<Vec<u8>>", IdlType::Bytes),
+            (
+                "std::sync::Arc<Option<Box<u8>>>",
+                IdlType::Option(Box::new(IdlType::U8)),
+            ),
+        ] {
+            let rust_ty =
+                RustType::try_from(ty).expect("Failed to parse rust type");
+            let idl_ty: IdlType =
+                rust_ty.try_into().expect("Failed to convert");
+            assert_eq!(idl_ty, idl_expected);
+        }
+    }
+
+    #[test]
+    fn idl_type_hash_dedup() {
+        let nested = || {
+            IdlType::Option(Box::new(IdlType::Vec(Box::new(IdlType::Tuple(
+                vec![IdlType::PublicKey, IdlType::Defined("Foo".to_string())],
+            )))))
+        };
+        let mut set = std::collections::HashSet::new();
+        set.insert(nested());
+        set.insert(nested());
+        assert_eq!(set.len(), 1);
+        set.insert(IdlType::Option(Box::new(IdlType::U8)));
+        assert_eq!(set.len(), 2);
+    }
 }
@@ -1,14 +1,19 @@
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 
 use anyhow::{Error, Result};
 use serde::{Deserialize, Serialize};
 use shank_macro_impl::{
+    converters::parse_error_into,
     custom_type::{CustomEnum, CustomStruct},
     parsed_enum::ParsedEnum,
-    parsed_struct::ParsedStruct,
+    parsed_struct::{rename_all_attr, ParsedStruct},
 };
 
-use crate::{idl_field::IdlField, idl_variant::IdlEnumVariant};
+use crate::{
+    idl_field::{FieldCase, IdlField},
+    idl_type::ConversionOptions,
+    idl_variant::IdlEnumVariant,
+};
 
 // -----------------
 // IdlTypeDefinitionTy
@@ -24,24 +29,54 @@ impl TryFrom<ParsedStruct> for IdlTypeDefinitionTy {
     type Error = Error;
 
     fn try_from(strct: ParsedStruct) -> Result<Self> {
+        IdlTypeDefinitionTy::from_struct_with(
+            strct,
+            ConversionOptions::default(),
+        )
+    }
+}
+
+impl TryFrom<ParsedEnum> for IdlTypeDefinitionTy {
+    type Error = Error;
+
+    fn try_from(enm: ParsedEnum) -> Result<Self> {
+        IdlTypeDefinitionTy::from_enum_with(enm, ConversionOptions::default())
+    }
+}
+
+impl IdlTypeDefinitionTy {
+    /// Converts the struct, converting the types of its fields with the provided options.
+    pub fn from_struct_with(
+        strct: ParsedStruct,
+        opts: ConversionOptions,
+    ) -> Result<Self> {
+        let case =
+            match rename_all_attr(&strct.attrs).map_err(parse_error_into)? {
+                Some(case) => case.parse()?,
+                None => FieldCase::default(),
+            };
         let fields = strct
             .fields
             .into_iter()
-            .map(IdlField::try_from)
+            .enumerate()
+            .map(|(index, field)| {
+                IdlField::from_struct_field_with(field, index, case, opts)
+            })
             .collect::<Result<Vec<IdlField>>>()?;
 
         Ok(Self::Struct { fields })
     }
-}
 
-impl TryFrom<ParsedEnum> for IdlTypeDefinitionTy {
-    type Error = Error;
-
-    fn try_from(enm: ParsedEnum) -> Result<Self> {
+    /// Converts the enum, converting the types of its variant fields with the provided
+    /// options.
+    pub fn from_enum_with(
+        enm: ParsedEnum,
+        opts: ConversionOptions,
+    ) -> Result<Self> {
         let variants = enm
             .variants
             .into_iter()
-            .map(IdlEnumVariant::try_from)
+            .map(|variant| IdlEnumVariant::from_variant_with(variant, opts))
             .collect::<Result<Vec<IdlEnumVariant>>>()?;
 
         Ok(Self::Enum { variants })
@@ -62,9 +97,7 @@ impl TryFrom<ParsedStruct> for IdlTypeDefinition {
     type Error = Error;
 
     fn try_from(strct: ParsedStruct) -> Result<Self> {
-        let name = strct.ident.to_string();
-        let ty: IdlTypeDefinitionTy = strct.try_into()?;
-        Ok(Self { ty, name })
+        IdlTypeDefinition::from_struct_with(strct, ConversionOptions::default())
     }
 }
 
@@ -72,9 +105,10 @@ impl TryFrom<CustomStruct> for IdlTypeDefinition {
     type Error = Error;
 
     fn try_from(strct: CustomStruct) -> Result<Self> {
-        let name = strct.ident.to_string();
-        let ty: IdlTypeDefinitionTy = strct.0.try_into()?;
-        Ok(Self { ty, name })
+        IdlTypeDefinition::from_struct_with(
+            strct.0,
+            ConversionOptions::default(),
+        )
     }
 }
 
@@ -82,8 +116,26 @@ impl TryFrom<CustomEnum> for IdlTypeDefinition {
     type Error = Error;
 
     fn try_from(enm: CustomEnum) -> Result<Self> {
+        IdlTypeDefinition::from_enum_with(enm.0, ConversionOptions::default())
+    }
+}
+
+impl IdlTypeDefinition {
+    pub fn from_struct_with(
+        strct: ParsedStruct,
+        opts: ConversionOptions,
+    ) -> Result<Self> {
+        let name = strct.ident.to_string();
+        let ty = IdlTypeDefinitionTy::from_struct_with(strct, opts)?;
+        Ok(Self { ty, name })
+    }
+
+    pub fn from_enum_with(
+        enm: ParsedEnum,
+        opts: ConversionOptions,
+    ) -> Result<Self> {
         let name = enm.ident.to_string();
-        let ty: IdlTypeDefinitionTy = enm.0.try_into()?;
+        let ty = IdlTypeDefinitionTy::from_enum_with(enm, opts)?;
         Ok(Self { ty, name })
     }
 }
@@ -1,15 +1,16 @@
 use std::convert::{TryFrom, TryInto};
 
 use serde::{Deserialize, Serialize};
-use shank_macro_impl::parsed_enum::{
-    ParsedEnumVariant, ParsedEnumVariantField,
+use shank_macro_impl::{
+    parsed_enum::{ParsedEnumVariant, ParsedEnumVariantField},
+    types::RustType,
 };
 
 use crate::{
     idl_field::{auto_docs, IdlField},
-    idl_type::IdlType,
+    idl_type::{ConversionOptions, IdlType},
 };
-use anyhow::{Error, Result};
+use anyhow::{anyhow, Error, Result};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(untagged)]
@@ -36,19 +37,41 @@ impl TryFrom<ParsedEnumVariant> for IdlEnumVariant {
     type Error = Error;
 
     fn try_from(variant: ParsedEnumVariant) -> Result<Self> {
-        let mut named_fields = Vec::new();
-        let mut tuple_fields = Vec::new();
+        IdlEnumVariant::from_variant_with(variant, ConversionOptions::default())
+    }
+}
 
+impl IdlEnumVariant {
+    /// Converts the variant, converting the types of its fields with the provided options.
+    pub fn from_variant_with(
+        variant: ParsedEnumVariant,
+        opts: ConversionOptions,
+    ) -> Result<Self> {
+        let tuple_members = variant
+            .fields
+            .iter()
+            .filter(|field| field.ident.is_none())
+            .map(|field| field.rust_type.clone())
+            .collect::<Vec<RustType>>();
+        let tuple_fields = convert_enum_tuple_variant(
+            &variant.ident.to_string(),
+            &tuple_members,
+            opts,
+        )?;
+
+        let mut named_fields = Vec::new();
         for field in &variant.fields {
-            let ty = IdlType::try_from(field.rust_type.clone())?;
-            match &field.ident {
-                Some(name) => named_fields.push(IdlField {
+            if let Some(name) = &field.ident {
+                let ty = IdlType::try_from_rust_type_with_options(
+                    field.rust_type.clone(),
+                    opts,
+                )?;
+                named_fields.push(IdlField {
                     name: name.to_string(),
                     ty,
                     attrs: None,
-                    docs: auto_docs(&field.rust_type),
-                }),
-                None => tuple_fields.push(ty),
+                    docs: auto_docs(&field.rust_type, opts),
+                });
             }
         }
 
@@ -67,3 +90,83 @@ impl TryFrom<ParsedEnumVariant> for IdlEnumVariant {
         })
     }
 }
+
+/// Converts each member of the tuple variant `name`, i.e. `A(u64, Pubkey)`, with the provided
+/// options, naming the variant and position of the first one that cannot be represented in the IDL.
+pub fn convert_enum_tuple_variant(
+    name: &str,
+    members: &[RustType],
+    opts: ConversionOptions,
+) -> Result<Vec<IdlType>> {
+    members
+        .iter()
+        .enumerate()
+        .map(|(idx, member)| {
+            IdlType::try_from_rust_type_with_options(member.clone(), opts)
+                .map_err(|err| {
+                    anyhow!(
+                        "Enum variant `{}` has a tuple member at position {} that cannot be represented in the IDL: {}",
+                        name,
+                        idx,
+                        err
+                    )
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::idl_type::TargetPointerWidth;
+    use shank_macro_impl::types::{Primitive, TypeKind};
+
+    #[test]
+    fn convert_enum_tuple_variant_supported_members() {
+        let members = vec![
+            RustType::owned_primitive("amount", Primitive::U64),
+            RustType::owned_custom_value("authority", "Pubkey"),
+        ];
+        assert_eq!(
+            convert_enum_tuple_variant(
+                "A",
+                &members,
+                ConversionOptions::default()
+            )
+            .unwrap(),
+            vec![IdlType::U64, IdlType::PublicKey]
+        );
+    }
+
+    #[test]
+    fn convert_enum_tuple_variant_with_options() {
+        let members = vec![RustType::owned_primitive("len", Primitive::USize)];
+        let opts = ConversionOptions {
+            pointer_width: TargetPointerWidth::Bits32,
+            ..ConversionOptions::default()
+        };
+        assert_eq!(
+            convert_enum_tuple_variant("A", &members, opts).unwrap(),
+            vec![IdlType::U32]
+        );
+    }
+
+    #[test]
+    fn convert_enum_tuple_variant_unsupported_member() {
+        let members = vec![
+            RustType::owned_primitive("amount", Primitive::U64),
+            RustType::owned("unit", TypeKind::Unit),
+        ];
+        let err = convert_enum_tuple_variant(
+            "A",
+            &members,
+            ConversionOptions::default(),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            err.contains("Enum variant `A` has a tuple member at position 1")
+        );
+        assert!(err.contains("IDL types cannot be Unit ()"));
+    }
+}
@@ -17,6 +17,11 @@ mod idl_variant;
 pub mod manifest;
 
 pub use file::*;
+pub use idl_field::{compute_offsets, FieldCase, IdlField};
+pub use idl_type::{
+    ConversionOptions, ExternalType, IdlType, TargetPointerWidth,
+    EXTERNAL_TYPES,
+};
 
 // -----------------
 // ParseIdlOpts
@@ -25,6 +30,12 @@ pub struct ParseIdlOpts {
     pub detect_custom_struct: DetectCustomTypeConfig,
     pub require_program_address: bool,
     pub program_address_override: Option<String>,
+    /// The pointer width that `usize` and `isize` fields are sized for.
+    pub pointer_width: TargetPointerWidth,
+    /// Emits `HashMap`s and `BTreeMap`s as vecs of key/value tuples.
+    pub maps_as_tuple_vecs: bool,
+    /// Types of other programs that are referenced as external types.
+    pub external_types: Vec<ExternalType>,
 }
 
 impl Default for ParseIdlOpts {
@@ -33,6 +44,9 @@ impl Default for ParseIdlOpts {
             detect_custom_struct: Default::default(),
             require_program_address: true,
             program_address_override: None,
+            pointer_width: Default::default(),
+            maps_as_tuple_vecs: false,
+            external_types: EXTERNAL_TYPES.to_vec(),
         }
     }
 }
@@ -57,6 +71,9 @@ pub fn extract_idl(file: &str, opts: ParseIdlOpts) -> Result<Option<Idl>> {
             detect_custom_struct: opts.detect_custom_struct,
             require_program_address: opts.require_program_address,
             program_address_override: opts.program_address_override,
+            pointer_width: opts.pointer_width,
+            maps_as_tuple_vecs: opts.maps_as_tuple_vecs,
+            external_types: opts.external_types,
         },
     )
 }
@@ -5,7 +5,8 @@ use std::{
 };
 
 use shank_idl::{
-    extract_idl, idl::Idl, parse_file, ParseIdlConfig, ParseIdlOpts,
+    extract_idl, idl::Idl, parse_file, ExternalType, ParseIdlConfig,
+    ParseIdlOpts, TargetPointerWidth,
 };
 
 fn fixtures_dir() -> PathBuf {
@@ -22,7 +23,8 @@ pub fn check_or_update_idl(idl: &Idl, json_path: &str) {
         .expect("Unable to parse expected json");
 
     if std::env::var("UPDATE_IDL").is_ok() {
-        let idl_json = idl.try_into_json().unwrap();
+        // keep the trailing newline of the checked in fixtures
+        let idl_json = format!("{}\n", idl.try_into_json().unwrap());
 
         let mut idl_json_file = File::create(&expected_json_file)
             .expect("Unable to create JSON file");
@@ -66,6 +68,1028 @@ fn account_from_single_file_padding() {
     check_or_update_idl(&idl, "single_file/padding.json");
 }
 
+#[test]
+fn account_from_single_file_reserved() {
+    let file = fixtures_dir().join("single_file").join("reserved.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/reserved.json");
+}
+
+#[test]
+fn account_from_single_file_encoding() {
+    let file = fixtures_dir().join("single_file").join("encoding.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/encoding.json");
+}
+
+#[test]
+fn account_from_single_file_encoding_invalid() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("encoding_invalid.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("#[idl_encoding]"));
+    assert!(err.contains("only applies to bytes"));
+}
+
+#[test]
+fn account_from_single_file_token_labels() {
+    let file = fixtures_dir().join("single_file").join("token_labels.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/token_labels.json");
+}
+
+#[test]
+fn account_from_single_file_token_labels_mismatch() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("token_labels_mismatch.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("has 2 #[idl_token_labels]"));
+    assert!(err.contains("array of size 3"));
+}
+
+#[test]
+fn account_from_single_file_stream() {
+    let file = fixtures_dir().join("single_file").join("stream.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/stream.json");
+}
+
+#[test]
+fn account_from_single_file_owner() {
+    let file = fixtures_dir().join("single_file").join("owner.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/owner.json");
+}
+
+#[test]
+fn account_from_single_file_rename_all() {
+    let file = fixtures_dir().join("single_file").join("rename_all.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/rename_all.json");
+}
+
+#[test]
+fn account_from_single_file_fixed_string() {
+    let file = fixtures_dir().join("single_file").join("fixed_string.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/fixed_string.json");
+}
+
+#[test]
+fn account_from_single_file_utf16() {
+    let file = fixtures_dir().join("single_file").join("utf16.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/utf16.json");
+}
+
+#[test]
+fn account_from_single_file_account_flags() {
+    let file = fixtures_dir().join("single_file").join("account_flags.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/account_flags.json");
+}
+
+#[test]
+fn account_from_single_file_default() {
+    let file = fixtures_dir().join("single_file").join("default.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/default.json");
+}
+
+#[test]
+fn account_from_single_file_unique() {
+    let file = fixtures_dir().join("single_file").join("unique.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/unique.json");
+}
+
+#[test]
+fn account_from_single_file_discriminator() {
+    let file = fixtures_dir().join("single_file").join("discriminator.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/discriminator.json");
+}
+
+#[test]
+fn account_from_single_file_version() {
+    let file = fixtures_dir().join("single_file").join("version.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/version.json");
+}
+
+#[test]
+fn account_from_single_file_version_invalid() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("version_invalid.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("#[idl_version]"));
+    assert!(err.contains("only applies to u8 or u16"));
+}
+
+#[test]
+fn account_from_single_file_histogram() {
+    let file = fixtures_dir().join("single_file").join("histogram.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/histogram.json");
+}
+
+#[test]
+fn account_from_single_file_range() {
+    let file = fixtures_dir().join("single_file").join("range.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/range.json");
+}
+
+#[test]
+fn account_from_single_file_range_invalid() {
+    let file = fixtures_dir().join("single_file").join("range_invalid.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("#[idl_range] min 100 is greater than max 1"));
+}
+
+#[test]
+fn account_from_single_file_magic() {
+    let file = fixtures_dir().join("single_file").join("magic.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/magic.json");
+}
+
+#[test]
+fn account_from_single_file_magic_mismatch() {
+    let file = fixtures_dir().join("single_file").join("magic_mismatch.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("#[idl_magic] of 2 bytes, but is an array of size 4"));
+}
+
+#[test]
+fn account_from_single_file_magic_wide_array() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("magic_wide_array.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(
+        "#[idl_magic] attribute which only applies to 4 byte arrays"
+    ));
+}
+
+#[test]
+fn account_from_single_file_membership() {
+    let file = fixtures_dir().join("single_file").join("membership.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/membership.json");
+}
+
+#[test]
+fn account_from_single_file_mime() {
+    let file = fixtures_dir().join("single_file").join("mime.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/mime.json");
+}
+
+#[test]
+fn account_from_single_file_leaf() {
+    let file = fixtures_dir().join("single_file").join("leaf.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/leaf.json");
+}
+
+#[test]
+fn account_from_single_file_deprecated() {
+    let file = fixtures_dir().join("single_file").join("deprecated.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/deprecated.json");
+}
+
+#[test]
+fn account_from_single_file_len_from() {
+    let file = fixtures_dir().join("single_file").join("len_from.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/len_from.json");
+}
+
+#[test]
+fn account_from_single_file_pubkey_keys() {
+    let file = fixtures_dir().join("single_file").join("pubkey_keys.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/pubkey_keys.json");
+}
+
+#[test]
+fn account_from_single_file_system_program_default() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("system_program_default.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/system_program_default.json");
+}
+
+#[test]
+fn account_from_single_file_units() {
+    let file = fixtures_dir().join("single_file").join("units.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/units.json");
+}
+
+#[test]
+fn account_from_single_file_merkle_root() {
+    let file = fixtures_dir().join("single_file").join("merkle_root.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/merkle_root.json");
+}
+
+#[test]
+fn account_from_single_file_envelope() {
+    let file = fixtures_dir().join("single_file").join("envelope.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/envelope.json");
+}
+
+#[test]
+fn account_from_single_file_datetime() {
+    let file = fixtures_dir().join("single_file").join("datetime.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/datetime.json");
+}
+
+#[test]
+fn account_from_single_file_sorted() {
+    let file = fixtures_dir().join("single_file").join("sorted.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/sorted.json");
+}
+
+#[test]
+fn account_from_single_file_relation() {
+    let file = fixtures_dir().join("single_file").join("relation.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/relation.json");
+}
+
+#[test]
+fn account_from_single_file_byte_flag() {
+    let file = fixtures_dir().join("single_file").join("byte_flag.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/byte_flag.json");
+}
+
+#[test]
+fn account_from_single_file_computed() {
+    let file = fixtures_dir().join("single_file").join("computed.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/computed.json");
+}
+
+#[test]
+fn account_from_single_file_compact_u16() {
+    let file = fixtures_dir().join("single_file").join("compact_u16.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/compact_u16.json");
+}
+
+#[test]
+fn account_from_single_file_tristate() {
+    let file = fixtures_dir().join("single_file").join("tristate.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/tristate.json");
+}
+
+#[test]
+fn account_from_single_file_pda() {
+    let file = fixtures_dir().join("single_file").join("pda.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/pda.json");
+}
+
+#[test]
+fn account_from_single_file_reinterpret() {
+    let file = fixtures_dir().join("single_file").join("reinterpret.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/reinterpret.json");
+}
+
+#[test]
+fn account_from_single_file_reinterpret_mismatch() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("reinterpret_mismatch.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(
+        "#[idl_reinterpret] to u64 of 8 bytes, but is an array of size 4"
+    ));
+}
+
+#[test]
+fn account_from_single_file_element_type() {
+    let file = fixtures_dir().join("single_file").join("element_type.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/element_type.json");
+}
+
+#[test]
+fn account_from_single_file_role_table() {
+    let file = fixtures_dir().join("single_file").join("role_table.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/role_table.json");
+}
+
+#[test]
+fn account_from_single_file_sensitive() {
+    let file = fixtures_dir().join("single_file").join("sensitive.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/sensitive.json");
+}
+
+#[test]
+fn account_from_single_file_pda_authority() {
+    let file = fixtures_dir().join("single_file").join("pda_authority.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/pda_authority.json");
+}
+
+#[test]
+fn account_from_single_file_string_enum() {
+    let file = fixtures_dir().join("single_file").join("string_enum.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/string_enum.json");
+}
+
+#[test]
+fn account_from_single_file_cid() {
+    let file = fixtures_dir().join("single_file").join("cid.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/cid.json");
+}
+
+#[test]
+fn account_from_single_file_remaining() {
+    let file = fixtures_dir().join("single_file").join("remaining.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/remaining.json");
+}
+
+#[test]
+fn account_from_single_file_proof() {
+    let file = fixtures_dir().join("single_file").join("proof.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/proof.json");
+}
+
+#[test]
+fn account_from_single_file_decimal_string() {
+    let file = fixtures_dir().join("single_file").join("decimal_string.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/decimal_string.json");
+}
+
+#[test]
+fn account_from_single_file_program_id() {
+    let file = fixtures_dir().join("single_file").join("program_id.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/program_id.json");
+}
+
+#[test]
+fn account_from_single_file_paginated() {
+    let file = fixtures_dir().join("single_file").join("paginated.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/paginated.json");
+}
+
+#[test]
+fn account_from_single_file_ata() {
+    let file = fixtures_dir().join("single_file").join("ata.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/ata.json");
+}
+
+#[test]
+fn account_from_single_file_none_default() {
+    let file = fixtures_dir().join("single_file").join("none_default.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/none_default.json");
+}
+
+#[test]
+fn account_from_single_file_none_default_invalid() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("none_default_invalid.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("#[idl_none_default] of 300 which is not a valid U8"));
+}
+
+#[test]
+fn account_from_single_file_bumps() {
+    let file = fixtures_dir().join("single_file").join("bumps.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/bumps.json");
+}
+
+#[test]
+fn account_from_single_file_max_entries() {
+    let file = fixtures_dir().join("single_file").join("max_entries.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/max_entries.json");
+}
+
+#[test]
+fn account_from_single_file_authority_of() {
+    let file = fixtures_dir().join("single_file").join("authority_of.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/authority_of.json");
+}
+
+#[test]
+fn account_from_single_file_uuid() {
+    let file = fixtures_dir().join("single_file").join("uuid.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/uuid.json");
+}
+
+#[test]
+fn account_from_single_file_uuid_invalid() {
+    let file = fixtures_dir().join("single_file").join("uuid_invalid.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(
+        "#[idl_uuid] attribute which only applies to [u8; 16], but its type is Array(U8, 8)"
+    ));
+}
+
+#[test]
+fn account_from_single_file_field_docs() {
+    let file = fixtures_dir().join("single_file").join("field_docs.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/field_docs.json");
+}
+
+#[test]
+fn account_from_single_file_tx_message() {
+    let file = fixtures_dir().join("single_file").join("tx_message.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/tx_message.json");
+}
+
+#[test]
+fn account_from_single_file_bps() {
+    let file = fixtures_dir().join("single_file").join("bps.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/bps.json");
+}
+
+#[test]
+fn account_from_single_file_serde_rename() {
+    let file = fixtures_dir().join("single_file").join("serde_rename.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/serde_rename.json");
+}
+
+#[test]
+fn account_from_single_file_close_destination() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("close_destination.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/close_destination.json");
+}
+
+#[test]
+fn account_from_single_file_secp256k1() {
+    let file = fixtures_dir().join("single_file").join("secp256k1.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/secp256k1.json");
+}
+
+#[test]
+fn account_from_single_file_secp256k1_invalid() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("secp256k1_invalid.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(
+        "#[idl_secp256k1] attribute which only applies to [u8; 33], but its type is Array(U8, 32)"
+    ));
+}
+
+#[test]
+fn account_from_single_file_idl_type_name_value() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("idl_type_name_value.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/idl_type_name_value.json");
+}
+
+#[test]
+fn account_from_single_file_queue() {
+    let file = fixtures_dir().join("single_file").join("queue.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/queue.json");
+}
+
+#[test]
+fn account_from_single_file_queue_invalid() {
+    let file = fixtures_dir().join("single_file").join("queue_invalid.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(
+        "#[idl_queue] expects \"fifo\" or \"lifo\", but got \"random\""
+    ));
+}
+
+#[test]
+fn account_from_single_file_unlock_time() {
+    let file = fixtures_dir().join("single_file").join("unlock_time.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/unlock_time.json");
+}
+
+#[test]
+fn account_from_single_file_eth_address() {
+    let file = fixtures_dir().join("single_file").join("eth_address.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/eth_address.json");
+}
+
+#[test]
+fn account_from_single_file_eth_address_invalid() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("eth_address_invalid.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(
+        "#[idl_eth_address] attribute which only applies to [u8; 20], but its type is Array(U8, 32)"
+    ));
+}
+
+#[test]
+fn account_from_single_file_versioned_blob() {
+    let file = fixtures_dir().join("single_file").join("versioned_blob.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/versioned_blob.json");
+}
+
+#[test]
+fn account_from_single_file_field_conversion_error() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("field_conversion_error.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err();
+    let err = format!("{:#}", err);
+    assert!(err.contains("Failed to convert field `members`"), "{}", err);
+    assert!(err.contains("unbalanced angle brackets"), "{}", err);
+}
+
+#[test]
+fn account_from_single_file_mint() {
+    let file = fixtures_dir().join("single_file").join("mint.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/mint.json");
+}
+
+#[test]
+fn account_from_single_file_u8_percent() {
+    let file = fixtures_dir().join("single_file").join("u8_percent.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/u8_percent.json");
+}
+
+#[test]
+fn account_from_single_file_distribution() {
+    let file = fixtures_dir().join("single_file").join("distribution.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/distribution.json");
+}
+
+#[test]
+fn account_from_single_file_keypair() {
+    let file = fixtures_dir().join("single_file").join("keypair.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/keypair.json");
+}
+
+#[test]
+fn account_from_single_file_keypair_invalid() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("keypair_invalid.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(
+        "#[idl_keypair] attribute which only applies to [u8; 64], but its type is Array(U8, 32)"
+    ));
+}
+
+#[test]
+fn account_from_single_file_uint_braced() {
+    let file = fixtures_dir().join("single_file").join("uint_braced.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/uint_braced.json");
+}
+
+#[test]
+fn account_from_single_file_uint_computed_bits() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("uint_computed_bits.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err();
+    let err = format!("{:#}", err);
+    assert!(err.contains("Failed to convert field `big`"), "{}", err);
+}
+
+#[test]
+fn account_from_single_file_pointer_width_32() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("pointer_width_32.rs");
+    let config = ParseIdlConfig {
+        pointer_width: TargetPointerWidth::Bits32,
+        ..ParseIdlConfig::optional_program_address()
+    };
+    let idl = parse_file(file, &config)
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/pointer_width_32.json");
+}
+
+#[test]
+fn account_from_single_file_maps_as_tuple_vecs() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("maps_as_tuple_vecs.rs");
+    let config = ParseIdlConfig {
+        maps_as_tuple_vecs: true,
+        ..ParseIdlConfig::optional_program_address()
+    };
+    let idl = parse_file(file, &config)
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/maps_as_tuple_vecs.json");
+}
+
+#[test]
+fn account_from_single_file_single_element_tuple() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("single_element_tuple.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/single_element_tuple.json");
+}
+
+#[test]
+fn account_from_single_file_local_value_type() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("local_value_type.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/local_value_type.json");
+}
+
+#[test]
+fn account_from_single_file_local_alias_type() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("local_alias_type.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/local_alias_type.json");
+}
+
+#[test]
+fn account_from_single_file_string_enum_unknown() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("string_enum_unknown.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains(
+        "#[idl_string_enum(Status)], but no fieldless enum `Status` is defined"
+    ));
+}
+
+#[test]
+fn account_from_single_file_string_enum_wide() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("string_enum_wide.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("#[idl_string_enum] attribute which only applies to u8")
+    );
+}
+
+#[test]
+fn account_from_single_file_idl_type_with_options() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("idl_type_with_options.rs");
+    let config = ParseIdlConfig {
+        pointer_width: TargetPointerWidth::Bits32,
+        maps_as_tuple_vecs: true,
+        ..ParseIdlConfig::optional_program_address()
+    };
+    let idl = parse_file(file, &config)
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/idl_type_with_options.json");
+}
+
+#[test]
+fn account_from_single_file_external_types() {
+    let file = fixtures_dir().join("single_file").join("external_types.rs");
+    let mut config = ParseIdlConfig::optional_program_address();
+    config.external_types.push(ExternalType::new(
+        "mpl_token_metadata::state::Metadata",
+        "mpl_token_metadata",
+        "Metadata",
+    ));
+    let idl = parse_file(file, &config)
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/external_types.json");
+}
+
+#[test]
+fn account_from_single_file_default_negative_unsigned() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("default_negative_unsigned.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("#[idl_default] of -1 which is not a valid U8"));
+}
+
+#[test]
+fn account_from_single_file_none_default_negative_overflow() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("none_default_negative_overflow.rs");
+    let err = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("#[idl_none_default] of -129 which is not a valid I8"));
+}
+
+#[test]
+fn account_from_single_file_local_wrapper_type() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("local_wrapper_type.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    check_or_update_idl(&idl, "single_file/local_wrapper_type.json");
+}
+
 #[test]
 fn account_from_single_file_idl_type() {
     let file = fixtures_dir().join("single_file").join("idl_type.rs");
@@ -0,0 +1,15 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithAccountFlags {
+    #[idl_account(signer, writable)]
+    payer: Pubkey,
+    #[idl_account(writable)]
+    destination: Pubkey,
+    #[idl_account(signer)]
+    authority: Pubkey,
+}
+
+#[derive(ShankAccount)]
+pub struct StructAccountWithInitIfNeeded {
+    #[idl_account(writable, init_if_needed)]
+    associated_token: Pubkey,
+}
@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithAta {
+    mint: Pubkey,
+    owner: Pubkey,
+    #[idl_ata(mint = "mint", owner = "owner")]
+    token_account: Pubkey,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithAuthorityOf {
+    vault: Pubkey,
+    #[idl_authority_of("vault")]
+    vault_authority: Pubkey,
+}
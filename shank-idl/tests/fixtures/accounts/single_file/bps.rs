@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithBps {
+    #[idl_bps]
+    seller_fee_basis_points: u16,
+    #[idl_bps]
+    protocol_fee: u32,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithBumps {
+    #[idl_bumps]
+    bumps: Vec<u8>,
+    authority: Pubkey,
+}
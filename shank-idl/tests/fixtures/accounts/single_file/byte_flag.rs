@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithByteFlag {
+    #[idl_byte_flag]
+    is_initialized: [u8; 1],
+    authority: Pubkey,
+}
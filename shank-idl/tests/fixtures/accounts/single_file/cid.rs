@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithCid {
+    #[idl_cid("dag-pb")]
+    content: Vec<u8>,
+    owner: Pubkey,
+}
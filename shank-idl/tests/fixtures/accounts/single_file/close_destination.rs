@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithCloseDestination {
+    authority: Pubkey,
+    #[idl_close_destination]
+    rent_receiver: Pubkey,
+}
@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithCompactU16 {
+    #[idl_compact_u16]
+    account_keys: Vec<Pubkey>,
+    #[idl_compact_u16]
+    data: Vec<u8>,
+}
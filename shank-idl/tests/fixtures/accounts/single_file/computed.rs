@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithComputedField {
+    deposited: u64,
+    withdrawn: u64,
+    #[idl_computed]
+    balance: u64,
+}
@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithDatetimes {
+    #[idl_datetime]
+    created_at: i64,
+    #[idl_datetime]
+    expires_at: u64,
+}
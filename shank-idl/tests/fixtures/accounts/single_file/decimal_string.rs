@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithDecimalStrings {
+    #[idl_decimal_string]
+    total_liquidity: u128,
+    #[idl_decimal_string]
+    cumulative_pnl: i128,
+}
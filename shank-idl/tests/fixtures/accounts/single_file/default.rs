@@ -0,0 +1,14 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithDefaults {
+    #[idl_default(0)]
+    count: u64,
+    #[idl_default(1_000u32)]
+    limit: u32,
+    #[idl_default(-1)]
+    offset: i32,
+    #[idl_default(true)]
+    enabled: bool,
+    #[idl_default("none")]
+    label: String,
+    authority: Pubkey,
+}
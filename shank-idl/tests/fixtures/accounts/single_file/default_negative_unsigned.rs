@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithNegativeUnsignedDefault {
+    #[idl_default(-1)]
+    count: u8,
+}
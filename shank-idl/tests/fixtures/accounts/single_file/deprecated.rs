@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithDeprecatedField {
+    #[idl_deprecated("use new_field")]
+    old_field: u64,
+    new_field: u128,
+}
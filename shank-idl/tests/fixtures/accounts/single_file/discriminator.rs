@@ -0,0 +1,17 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithDiscriminator {
+    discriminator: [u8; 8],
+    authority: Pubkey,
+}
+
+#[derive(ShankAccount)]
+pub struct StructAccountWithShortDiscriminator {
+    discriminator: [u8; 1],
+    authority: Pubkey,
+}
+
+#[derive(ShankAccount)]
+pub struct StructAccountWithTrailingDiscriminator {
+    authority: Pubkey,
+    discriminator: [u8; 8],
+}
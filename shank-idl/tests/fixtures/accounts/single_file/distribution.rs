@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithDistribution {
+    #[idl_distribution]
+    holders: Vec<(Pubkey, u64)>,
+    mint: Pubkey,
+}
@@ -0,0 +1,8 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithDefinedVecs {
+    positions: Vec<Position>,
+    orders: Vec<crate::state::Order>,
+    amounts: Vec<u64>,
+    keys: Vec<Pubkey>,
+    pending: VecDeque<Position>,
+}
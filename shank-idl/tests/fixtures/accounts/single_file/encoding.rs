@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithEncoding {
+    #[idl_encoding("protobuf")]
+    payload: Vec<u8>,
+    #[idl_encoding("bincode")]
+    config: Vec<u8>,
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithInvalidEncoding {
+    #[idl_encoding("protobuf")]
+    payload: u64,
+}
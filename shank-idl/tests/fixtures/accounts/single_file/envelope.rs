@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithEnvelope {
+    #[idl_envelope("eip712")]
+    message: Vec<u8>,
+    signature: [u8; 64],
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithEthAddress {
+    #[idl_eth_address]
+    recipient: [u8; 20],
+    sender: Pubkey,
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithInvalidEthAddress {
+    #[idl_eth_address]
+    recipient: [u8; 32],
+}
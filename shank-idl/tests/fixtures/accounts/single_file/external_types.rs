@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithExternalTypes {
+    pub mint: spl_token::state::Mint,
+    pub metadata: mpl_token_metadata::state::Metadata,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithUnconvertibleField {
+    authority: Pubkey,
+    #[idl_type = "vec<u8"]
+    members: OpaqueMembers,
+}
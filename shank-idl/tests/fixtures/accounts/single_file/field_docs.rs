@@ -0,0 +1,10 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithFieldDocs {
+    /// The price of the listing
+    ///
+    /// Denominated in the quote mint
+    price: Decimal<2, u64>,
+    /// The authority that may update the price
+    authority: Pubkey,
+    rate: Decimal<4, u32>,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithFixedString {
+    #[idl_fixed_string]
+    name: [u8; 16],
+    symbol: [u8; 8],
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithHistogram {
+    #[idl_histogram]
+    fill_sizes: Vec<(u64, u64)>,
+    authority: Pubkey,
+}
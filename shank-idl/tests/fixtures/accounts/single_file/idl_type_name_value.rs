@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct AccountWithIdlTypeNameValue {
+    #[idl_type = "u64"]
+    amount: OpaqueAmount,
+    #[idl_type = "Vec<Pubkey>"]
+    members: OpaqueMembers,
+}
@@ -0,0 +1,13 @@
+pub struct Position {
+    pub x: u64,
+}
+
+#[derive(ShankAccount)]
+pub struct StructAccountWithIdlTypeOptions {
+    #[idl_type = "usize"]
+    pub len: u64,
+    #[idl_type = "hashMap<string, u8>"]
+    pub labels: Vec<u8>,
+    #[idl_type = "bytes"]
+    pub positions: Vec<Position>,
+}
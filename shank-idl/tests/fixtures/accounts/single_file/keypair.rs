@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithKeypair {
+    #[idl_keypair]
+    session_key: [u8; 64],
+    owner: Pubkey,
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithInvalidKeypair {
+    #[idl_keypair]
+    session_key: [u8; 32],
+}
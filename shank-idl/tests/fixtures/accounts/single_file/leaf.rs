@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithLeaf {
+    #[idl_leaf]
+    leaf_hash: [u8; 32],
+    leaf_index: u32,
+}
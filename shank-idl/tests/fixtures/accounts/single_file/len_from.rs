@@ -0,0 +1,9 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithLenFrom {
+    num_signers: u8,
+    #[idl_len_from = "num_signers"]
+    pub signers: Vec<Pubkey>,
+    data_len: u32,
+    #[idl_len_from = "data_len"]
+    pub data: Vec<u8>,
+}
@@ -0,0 +1,12 @@
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Epoch {
+    pub start: u64,
+    pub end: u64,
+}
+
+#[derive(ShankAccount)]
+pub struct Schedule {
+    pub current: Epoch,
+    pub slot: Slot,
+    pub deadline: solana_program::clock::UnixTimestamp,
+}
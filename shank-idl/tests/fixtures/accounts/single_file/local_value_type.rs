@@ -0,0 +1,10 @@
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Value {
+    pub amount: u64,
+}
+
+#[derive(ShankAccount)]
+pub struct Metadata {
+    pub values: HashMap<String, Value>,
+    pub json: HashMap<String, serde_json::Value>,
+}
@@ -0,0 +1,11 @@
+#[derive(BorshSerialize, BorshDeserialize)]
+pub struct Rc<T> {
+    pub count: u32,
+    pub value: T,
+}
+
+#[derive(ShankAccount)]
+pub struct StructAccountWithLocalWrapper {
+    pub counted: Rc<u64>,
+    pub boxed: Box<u64>,
+}
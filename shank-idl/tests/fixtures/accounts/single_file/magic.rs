@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithMagic {
+    #[idl_magic([0xDE, 0xAD, 0xBE, 0xEF])]
+    magic: [u8; 4],
+    version: u8,
+}
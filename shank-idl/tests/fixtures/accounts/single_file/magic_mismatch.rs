@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithMismatchedMagic {
+    #[idl_magic([0xDE, 0xAD])]
+    magic: [u8; 4],
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithWideMagic {
+    #[idl_magic([0xDE, 0xAD, 0xBE, 0xEF])]
+    magic: [u8; 8],
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct Ledger {
+    pub balances: HashMap<Pubkey, u64>,
+    pub labels: BTreeMap<u8, String>,
+}
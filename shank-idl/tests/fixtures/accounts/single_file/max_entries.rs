@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithMaxEntries {
+    #[idl_max_entries(100)]
+    balances: BTreeMap<Pubkey, u64>,
+    #[idl_max_entries(10)]
+    admins: HashSet<Pubkey>,
+}
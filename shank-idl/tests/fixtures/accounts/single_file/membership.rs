@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithMembership {
+    #[idl_membership]
+    allowlist: HashMap<Pubkey, bool>,
+    authority: Pubkey,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithMerkleRoot {
+    #[idl_merkle_root(depth = 14)]
+    root: [u8; 32],
+    sequence: u64,
+}
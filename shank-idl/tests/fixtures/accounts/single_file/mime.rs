@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithMime {
+    #[idl_mime("image/png")]
+    image: Vec<u8>,
+    #[idl_mime("application/vnd.custom+json; charset=utf-8")]
+    attributes: Vec<u8>,
+}
@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithMint {
+    #[idl_mint(decimals_field = "decimals")]
+    mint: Pubkey,
+    decimals: u8,
+    amount: u64,
+}
@@ -0,0 +1,8 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithNoneDefault {
+    #[idl_none_default(100)]
+    fee_bps: Option<u16>,
+    #[idl_none_default(-5)]
+    skew: Option<i16>,
+    authority: Pubkey,
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithInvalidNoneDefault {
+    #[idl_none_default(300)]
+    fee_bps: Option<u8>,
+}
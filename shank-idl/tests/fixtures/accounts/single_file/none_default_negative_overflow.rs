@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithOverflowingNoneDefault {
+    #[idl_none_default(-129)]
+    skew: Option<i8>,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithOwner {
+    #[idl_owner("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")]
+    token_account: Pubkey,
+    authority: Pubkey,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithPaginated {
+    #[idl_paginated(page_size = 50)]
+    entries: Vec<Pubkey>,
+    authority: Pubkey,
+}
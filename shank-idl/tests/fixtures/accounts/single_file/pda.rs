@@ -0,0 +1,8 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithPdas {
+    authority: Pubkey,
+    #[idl_pda(program = "self", seeds = ["vault", authority])]
+    vault: Pubkey,
+    #[idl_pda(seeds = ["metadata", authority])]
+    metadata: Pubkey,
+}
@@ -0,0 +1,9 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithPdaAuthority {
+    #[idl_pda_authority(bump = "vault_bump")]
+    vault_authority: [u8; 32],
+    vault_bump: u8,
+    #[idl_pda_authority(bump = "mint_bump")]
+    mint_authority: Pubkey,
+    mint_bump: u8,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct Registry {
+    pub len: usize,
+    pub offset: isize,
+    pub lens: Vec<usize>,
+}
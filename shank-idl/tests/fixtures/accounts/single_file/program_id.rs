@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithProgramId {
+    #[idl_program_id]
+    program: Pubkey,
+    authority: Pubkey,
+}
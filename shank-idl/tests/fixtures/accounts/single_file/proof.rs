@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithProofs {
+    #[idl_proof]
+    proof: Vec<[u8; 32]>,
+    #[idl_proof]
+    raw_proof: Vec<Vec<u8>>,
+}
@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithPubkeyKeys {
+    #[idl_pubkey_keys]
+    balances: HashMap<String, u64>,
+    #[idl_pubkey_keys]
+    weights: BTreeMap<String, u16>,
+}
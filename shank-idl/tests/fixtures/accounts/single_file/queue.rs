@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithQueue {
+    #[idl_queue("fifo")]
+    pending: Vec<u64>,
+    #[idl_queue("lifo")]
+    undo: VecDeque<Pubkey>,
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithInvalidQueue {
+    #[idl_queue("random")]
+    pending: Vec<u64>,
+}
@@ -0,0 +1,9 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithRanges {
+    #[idl_range(min = 1, max = 100)]
+    percentage: u8,
+    #[idl_range(min = 10)]
+    min_deposit: u64,
+    #[idl_range(max = 5)]
+    max_retries: u16,
+}
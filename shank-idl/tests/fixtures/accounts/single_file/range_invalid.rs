@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithInvalidRange {
+    #[idl_range(min = 100, max = 1)]
+    percentage: u8,
+}
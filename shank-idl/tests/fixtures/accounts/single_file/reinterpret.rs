@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithReinterpretedBytes {
+    #[idl_reinterpret(u64, big)]
+    sequence: [u8; 8],
+    #[idl_reinterpret(i32, little)]
+    offset: [u8; 4],
+}
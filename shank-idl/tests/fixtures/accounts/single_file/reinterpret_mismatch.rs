@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithMismatchedReinterpret {
+    #[idl_reinterpret(u64, big)]
+    sequence: [u8; 4],
+}
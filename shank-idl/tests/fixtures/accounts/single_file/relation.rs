@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithRelations {
+    #[idl_relation(name = "mint", account = "Mint")]
+    mint: Pubkey,
+    #[idl_relation(name = "vault", account = "TokenAccount")]
+    vault: Pubkey,
+}
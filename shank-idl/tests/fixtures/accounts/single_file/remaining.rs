@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithRemainingAccounts {
+    #[idl_remaining(index = 0)]
+    oracle: Pubkey,
+    #[idl_remaining(index = 1)]
+    fallback_oracle: Pubkey,
+}
@@ -0,0 +1,12 @@
+#[derive(ShankAccount)]
+#[idl(rename_all = "snake_case")]
+pub struct SnakeCaseAccount {
+    update_authority: Pubkey,
+    primary_sale_happened: bool,
+}
+
+#[derive(ShankAccount)]
+pub struct DefaultCaseAccount {
+    update_authority: Pubkey,
+    primary_sale_happened: bool,
+}
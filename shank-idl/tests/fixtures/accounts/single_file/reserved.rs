@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithReserved {
+    count: u8,
+    #[idl_reserved]
+    _reserved: [u8; 128],
+}
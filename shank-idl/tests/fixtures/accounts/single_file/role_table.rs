@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithRoleTable {
+    #[idl_role_table]
+    roles: HashMap<u8, Pubkey>,
+    admin: Pubkey,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithSecp256k1 {
+    #[idl_secp256k1]
+    guardian: [u8; 33],
+    authority: Pubkey,
+}
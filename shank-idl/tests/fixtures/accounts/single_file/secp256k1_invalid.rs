@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithInvalidSecp256k1 {
+    #[idl_secp256k1]
+    guardian: [u8; 32],
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithSensitiveField {
+    #[idl_sensitive]
+    encrypted_secret: [u8; 64],
+    owner: Pubkey,
+}
@@ -0,0 +1,8 @@
+#[derive(ShankAccount, Serialize, Deserialize)]
+pub struct StructAccountWithSerdeRename {
+    #[serde(rename = "customName")]
+    foo_bar: u64,
+    #[serde(default, rename = "other_name")]
+    other_field: u8,
+    baz_qux: Pubkey,
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithSingleElementTuple {
+    pub wrapped: (u32,),
+    pub pairs: Vec<(u8,)>,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithSortedVec {
+    #[idl_sorted]
+    slots: Vec<u64>,
+    authority: Pubkey,
+}
@@ -0,0 +1,10 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithStreams {
+    count: u8,
+    #[idl_stream]
+    entries: Vec<u64>,
+    #[idl_stream]
+    blob: Vec<u8>,
+    #[idl_stream]
+    description: String,
+}
@@ -0,0 +1,13 @@
+#[repr(u8)]
+pub enum Status {
+    Frozen = 1,
+    Active = 0,
+    Closed = 2,
+}
+
+#[derive(ShankAccount)]
+pub struct StructAccountWithStringEnum {
+    #[idl_string_enum(Status)]
+    status: u8,
+    authority: Pubkey,
+}
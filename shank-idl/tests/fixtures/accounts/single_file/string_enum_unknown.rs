@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithUnknownStringEnum {
+    #[idl_string_enum(Status)]
+    status: u8,
+}
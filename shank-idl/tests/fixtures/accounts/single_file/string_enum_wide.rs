@@ -0,0 +1,11 @@
+#[repr(u16)]
+pub enum Status {
+    Active,
+    Closed,
+}
+
+#[derive(ShankAccount)]
+pub struct StructAccountWithWideStringEnum {
+    #[idl_string_enum(Status)]
+    status: u16,
+}
@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithSystemProgramDefaults {
+    #[idl_system_program_default]
+    delegate: Option<Pubkey>,
+    #[idl_system_program_default]
+    close_authority: Pubkey,
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct MultiTokenVault {
+    #[idl_token_labels("SOL", "USDC")]
+    balances: [u64; 3],
+}
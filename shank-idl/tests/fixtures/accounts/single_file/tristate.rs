@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithTristate {
+    #[idl_tristate]
+    verified: Option<bool>,
+    authority: Pubkey,
+}
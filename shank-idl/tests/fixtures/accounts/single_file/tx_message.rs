@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithTxMessage {
+    #[idl_tx_message(version = 0)]
+    message: Vec<u8>,
+    proposer: Pubkey,
+}
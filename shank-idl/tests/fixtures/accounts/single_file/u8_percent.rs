@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithU8Percent {
+    #[idl_u8_percent]
+    share: u8,
+    owner: Pubkey,
+}
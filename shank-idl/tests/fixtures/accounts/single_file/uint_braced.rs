@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithBracedUint {
+    big: Uint<{ 256 }, 4>,
+    plain: Uint<256, 4>,
+}
@@ -0,0 +1,4 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithComputedUintBits {
+    big: Uint<{ 4 * 64 }, 4>,
+}
@@ -0,0 +1,8 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithUniqueItems {
+    #[idl_unique]
+    allowlist: Vec<Pubkey>,
+    #[idl_unique]
+    mints: BTreeSet<Pubkey>,
+    authority: Pubkey,
+}
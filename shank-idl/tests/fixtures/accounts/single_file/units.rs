@@ -0,0 +1,7 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithUnits {
+    #[idl_units("lamports")]
+    rent: u64,
+    #[idl_units("basis points")]
+    fee: u16,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithUnlockTime {
+    #[idl_unlock_time]
+    unlock_at: i64,
+    beneficiary: Pubkey,
+}
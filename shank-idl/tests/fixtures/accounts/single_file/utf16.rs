@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithUtf16 {
+    #[idl_utf16]
+    legacy_name: Vec<u16>,
+}
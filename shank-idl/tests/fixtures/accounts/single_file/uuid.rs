@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithUuid {
+    #[idl_uuid]
+    id: [u8; 16],
+    authority: Pubkey,
+}
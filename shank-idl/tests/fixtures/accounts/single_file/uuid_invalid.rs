@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithInvalidUuid {
+    #[idl_uuid]
+    id: [u8; 8],
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithVersion {
+    #[idl_version]
+    version: u8,
+    authority: Pubkey,
+}
@@ -0,0 +1,5 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithInvalidVersion {
+    #[idl_version]
+    version: u64,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankAccount)]
+pub struct StructAccountWithVersionedBlob {
+    #[idl_versioned_blob(prefix_bytes = 1)]
+    payload: Vec<u8>,
+    authority: Pubkey,
+}
@@ -0,0 +1,6 @@
+#[derive(ShankInstruction)]
+pub enum Instruction {
+    #[account(0, name = "oracle", mut, desc = "The price feed")]
+    #[account(1, name = "authority", sig)]
+    UpdatePrice { price: f64, confidence: f32 },
+}
@@ -0,0 +1,6 @@
+#[derive(ShankType)]
+pub struct UpdateConfigArgs {
+    #[idl_optional_update]
+    pub fee_bps: Option<u64>,
+    pub authority: Option<Pubkey>,
+}
@@ -0,0 +1,57 @@
+use std::{collections::HashSet, str::FromStr};
+
+use shank_idl::{compute_offsets, FieldCase, IdlField, IdlType};
+
+#[test]
+fn idl_type_from_str_and_to_rust_string() {
+    let ty = IdlType::from_str("Vec<(u8, Pubkey)>").unwrap();
+    assert_eq!(
+        ty,
+        IdlType::Vec(Box::new(IdlType::Tuple(vec![
+            IdlType::U8,
+            IdlType::PublicKey
+        ])))
+    );
+    assert_eq!(ty.to_rust_string(), "Vec<(u8, Pubkey)>");
+    assert_eq!(IdlType::from_str(&ty.to_rust_string()).unwrap(), ty);
+}
+
+#[test]
+fn idl_type_sizes_and_defined() {
+    let ty = IdlType::from_str("hashMap<string, Position>").unwrap();
+    assert_eq!(ty.fixed_size(), None);
+    assert_eq!(
+        IdlType::from_str("[u64; 4]").unwrap().fixed_size(),
+        Some(32)
+    );
+
+    let mut defined = vec![];
+    ty.collect_defined(&mut defined);
+    assert_eq!(defined, vec!["Position".to_string()]);
+}
+
+#[test]
+fn idl_type_in_set() {
+    let tys = ["u8", "u8", "Pubkey", "publicKey"]
+        .iter()
+        .map(|s| IdlType::from_str(s).unwrap())
+        .collect::<HashSet<IdlType>>();
+    assert_eq!(tys.len(), 2);
+}
+
+#[test]
+fn idl_field_offsets() {
+    let fields = [("discriminator", "[u8; 8]"), ("amount", "u64")]
+        .iter()
+        .map(|(name, ty)| IdlField {
+            name: FieldCase::default().apply(name),
+            ty: IdlType::from_str(ty).unwrap(),
+            attrs: None,
+            docs: None,
+        })
+        .collect::<Vec<IdlField>>();
+    assert_eq!(
+        compute_offsets(&fields).unwrap(),
+        vec![("discriminator".to_string(), 0), ("amount".to_string(), 8)]
+    );
+}
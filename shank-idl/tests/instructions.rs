@@ -77,6 +77,23 @@ fn instruction_from_single_file_with_multiple_args() {
     assert_eq!(idl, expected_idl);
 }
 
+#[test]
+fn instruction_from_single_file_with_float_args() {
+    let file = fixtures_dir()
+        .join("single_file")
+        .join("instruction_with_float_args.rs");
+    let idl = parse_file(file, &ParseIdlConfig::optional_program_address())
+        .expect("Parsing should not fail")
+        .expect("File contains IDL");
+
+    let expected_idl: Idl = serde_json::from_str(include_str!(
+        "./fixtures/instructions/single_file/instruction_with_float_args.json"
+    ))
+    .unwrap();
+
+    assert_eq!(idl, expected_idl);
+}
+
 #[test]
 fn instruction_from_single_file_with_idl_instructions() {
     let file = fixtures_dir()
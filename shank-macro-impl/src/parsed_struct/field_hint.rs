@@ -0,0 +1,738 @@
+use syn::{
+    punctuated::Punctuated, spanned::Spanned, Attribute, Error as ParseError,
+    Expr, ExprArray, ExprAssign, ExprLit, ExprPath, ExprUnary, Ident, Lit,
+    Meta, MetaNameValue, NestedMeta, Result as ParseResult, Token, UnOp,
+};
+
+// -----------------
+// FieldHint
+// -----------------
+/// Hints provided via `#[idl_*]` field attributes.
+/// They don't change the type of the field, but are surfaced as `@` docs in the IDL so that
+/// clients know how to interpret its data.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FieldHint {
+    /// `#[idl_reserved]` marks bytes that are reserved for future expansion
+    Reserved,
+    /// `#[idl_encoding("protobuf")]` names the encoding of the contained bytes
+    Encoding(String),
+    /// `#[idl_token_labels("SOL", "USDC")]` labels each index of a balances array
+    TokenLabels(Vec<String>),
+    /// `#[idl_stream]` signals that the data is large and should be fetched separately
+    Stream,
+    /// `#[idl_owner("Tokenkeg...")]` documents the program expected to own the account
+    Owner(String),
+    /// `#[idl_fixed_string]` marks a byte array holding null-padded text
+    FixedString,
+    /// `#[idl_utf16]` marks a `Vec<u16>` holding UTF-16 encoded text
+    Utf16,
+    /// `#[idl_account(signer, writable, init_if_needed)]` documents how the account of the public key is passed to instructions
+    Account {
+        signer: bool,
+        writable: bool,
+        init_if_needed: bool,
+    },
+    /// `#[idl_default(0)]` captures the literal default value of the field, negative integers included
+    Default(String),
+    /// `#[idl_unique]` requires the items of a collection to be unique
+    Unique,
+    /// `#[idl_version]` marks the field holding the version of a versioned account
+    Version,
+    /// `#[idl_histogram]` marks a vec of `(bucket, count)` pairs
+    Histogram,
+    /// `#[idl_optional_update]` marks an option where `None` leaves the current value unchanged
+    OptionalUpdate,
+    /// `#[idl_range(min = 1, max = 100)]` constrains the values of an integer
+    Range {
+        min: Option<i128>,
+        max: Option<i128>,
+    },
+    /// `#[idl_magic([0xDE, 0xAD, 0xBE, 0xEF])]` documents the magic number a header must hold
+    Magic(Vec<u8>),
+    /// `#[idl_membership]` marks a map to `bool` that is used as a set
+    Membership,
+    /// `#[idl_mime("image/png")]` names the MIME type of the contained bytes
+    Mime(String),
+    /// `#[idl_leaf]` marks a 32 byte hash that is a leaf of a compressed account tree
+    Leaf,
+    /// `#[idl_deprecated("use new_field")]` warns clients not to use the field anymore
+    Deprecated(String),
+    /// `#[idl_len_from = "num_signers"]` names the field holding the number of items of a collection
+    LenFrom(String),
+    /// `#[idl_pubkey_keys]` marks a string keyed map whose keys are base58 encoded public keys
+    PubkeyKeys,
+    /// `#[idl_system_program_default]` documents that a missing public key semantically defaults to the system program
+    SystemProgramDefault,
+    /// `#[idl_units("lamports")]` names the unit of an integer
+    Units(String),
+    /// `#[idl_merkle_root(depth = 14)]` marks the root hash of a merkle tree of the provided depth
+    MerkleRoot { depth: usize },
+    /// `#[idl_envelope("eip712")]` names the typed-data envelope of a signed message
+    Envelope(String),
+    /// `#[idl_datetime]` marks an integer holding a unix timestamp in seconds
+    Datetime,
+    /// `#[idl_sorted]` marks a vec whose items are kept in sorted order
+    Sorted,
+    /// `#[idl_relation(name = "mint", account = "Mint")]` names the relation of a public key to another account type
+    Relation { name: String, account: String },
+    /// `#[idl_byte_flag]` marks a single byte array interpreted as a boolean
+    ByteFlag,
+    /// `#[idl_computed]` marks a field that is derived off-chain and must not be written by clients
+    Computed,
+    /// `#[idl_compact_u16]` marks a collection prefixed with its length as Solana compact-u16 instead of a u32
+    CompactU16,
+    /// `#[idl_tristate]` marks an `Option<bool>` whose `None` is a third state
+    Tristate,
+    /// `#[idl_pda(program = "self", seeds = ["vault", authority])]` documents how the public key is derived as PDA
+    Pda {
+        program: Option<String>,
+        seeds: Vec<String>,
+    },
+    /// `#[idl_reinterpret(u64, big)]` marks a byte array holding an integer of the provided endianness
+    Reinterpret { int: String, endian: String },
+    /// `#[idl_role_table]` marks a map from role ids to the public keys holding them
+    RoleTable,
+    /// `#[idl_sensitive]` marks a field holding a secret that must not be logged
+    Sensitive,
+    /// `#[idl_pda_authority(bump = "vault_bump")]` marks a PDA authority and names the field holding its bump
+    PdaAuthority { bump: String },
+    /// `#[idl_string_enum(Status)]` names the `#[repr(u8)]` enum whose variant is stored in the `u8`
+    StringEnum(String),
+    /// `#[idl_cid("dag-pb")]` marks bytes holding an IPFS CID of the provided codec
+    Cid(String),
+    /// `#[idl_remaining(index = 0)]` marks a public key passed as remaining account at the provided index
+    Remaining { index: usize },
+    /// `#[idl_proof]` marks a vec of hashes forming a merkle proof
+    Proof,
+    /// `#[idl_decimal_string]` marks a 128-bit integer that clients should store as decimal string
+    DecimalString,
+    /// `#[idl_program_id]` marks a public key that holds the id of the program itself
+    ProgramId,
+    /// `#[idl_paginated(page_size = 50)]` marks a vec that clients should fetch in pages
+    Paginated { page_size: usize },
+    /// `#[idl_ata(mint = "mint", owner = "owner")]` marks an associated token account derived from the mint and owner fields
+    Ata { mint: String, owner: String },
+    /// `#[idl_none_default(100)]` marks an optional integer whose `None` implies the provided value
+    NoneDefault(String),
+    /// `#[idl_bumps]` marks a vec of bytes holding PDA bump seeds
+    Bumps,
+    /// `#[idl_max_entries(100)]` marks a map or set that holds at most the provided number of entries
+    MaxEntries(usize),
+    /// `#[idl_authority_of("vault")]` marks a public key that is the authority of the provided field
+    AuthorityOf(String),
+    /// `#[idl_uuid]` marks a 16 byte array that clients should format as a UUID string
+    Uuid,
+    /// `#[idl_tx_message(version = 0)]` marks bytes holding a serialized transaction message of the provided version
+    TxMessage { version: usize },
+    /// `#[idl_bps]` marks an integer holding basis points, i.e. 1/10000ths
+    Bps,
+    /// `#[idl_close_destination]` marks a public key receiving the lamports when the account is closed
+    CloseDestination,
+    /// `#[idl_secp256k1]` marks a 33 byte array holding a compressed secp256k1 public key
+    Secp256k1,
+    /// `#[idl_queue("fifo")]` marks a vec used as queue with `fifo` or `lifo` semantics
+    Queue(String),
+    /// `#[idl_unlock_time]` marks a unix timestamp until which funds are locked up
+    UnlockTime,
+    /// `#[idl_eth_address]` marks a 20 byte array holding an Ethereum address
+    EthAddress,
+    /// `#[idl_versioned_blob(prefix_bytes = 1)]` marks bytes whose leading bytes hold the schema version of the rest
+    VersionedBlob { prefix_bytes: usize },
+    /// `#[idl_mint(decimals_field = "decimals")]` marks a mint whose decimals are stored in the provided field
+    Mint { decimals_field: String },
+    /// `#[idl_u8_percent]` marks a `u8` holding a percentage scaled to 0..=255
+    U8Percent,
+    /// `#[idl_distribution]` marks a vec of public keys paired with their share, i.e. token holders
+    Distribution,
+    /// `#[idl_keypair]` marks a 64 byte array holding a secret and public key pair
+    Keypair,
+}
+
+impl FieldHint {
+    /// Returns the hint for the provided attribute or `None` if it isn't an `#[idl_*]` hint.
+    pub fn try_from_attr(attr: &Attribute) -> ParseResult<Option<Self>> {
+        let name = match attr.path.get_ident() {
+            Some(ident) => ident.to_string(),
+            None => return Ok(None),
+        };
+        let hint = match name.as_str() {
+            "idl_reserved" => FieldHint::Reserved,
+            "idl_encoding" => FieldHint::Encoding(single_str_arg(attr, &name)?),
+            "idl_token_labels" => {
+                FieldHint::TokenLabels(str_list_args(attr, &name)?)
+            }
+            "idl_stream" => FieldHint::Stream,
+            "idl_owner" => FieldHint::Owner(single_str_arg(attr, &name)?),
+            "idl_fixed_string" => FieldHint::FixedString,
+            "idl_utf16" => FieldHint::Utf16,
+            "idl_account" => account_hint(attr, &name)?,
+            "idl_default" => FieldHint::Default(single_lit_arg(attr, &name)?),
+            "idl_unique" => FieldHint::Unique,
+            "idl_version" => FieldHint::Version,
+            "idl_histogram" => FieldHint::Histogram,
+            "idl_optional_update" => FieldHint::OptionalUpdate,
+            "idl_range" => range_hint(attr, &name)?,
+            "idl_magic" => FieldHint::Magic(byte_array_arg(attr, &name)?),
+            "idl_membership" => FieldHint::Membership,
+            "idl_mime" => FieldHint::Mime(single_str_arg(attr, &name)?),
+            "idl_leaf" => FieldHint::Leaf,
+            "idl_deprecated" => {
+                FieldHint::Deprecated(single_str_arg(attr, &name)?)
+            }
+            "idl_len_from" => {
+                FieldHint::LenFrom(name_value_str_arg(attr, &name)?)
+            }
+            "idl_pubkey_keys" => FieldHint::PubkeyKeys,
+            "idl_system_program_default" => FieldHint::SystemProgramDefault,
+            "idl_units" => FieldHint::Units(single_str_arg(attr, &name)?),
+            "idl_merkle_root" => FieldHint::MerkleRoot {
+                depth: int_name_value_arg(attr, &name, "depth")?,
+            },
+            "idl_envelope" => FieldHint::Envelope(single_str_arg(attr, &name)?),
+            "idl_datetime" => FieldHint::Datetime,
+            "idl_sorted" => FieldHint::Sorted,
+            "idl_relation" => relation_hint(attr, &name)?,
+            "idl_byte_flag" => FieldHint::ByteFlag,
+            "idl_computed" => FieldHint::Computed,
+            "idl_compact_u16" => FieldHint::CompactU16,
+            "idl_tristate" => FieldHint::Tristate,
+            "idl_pda" => pda_hint(attr, &name)?,
+            "idl_reinterpret" => reinterpret_hint(attr, &name)?,
+            "idl_role_table" => FieldHint::RoleTable,
+            "idl_sensitive" => FieldHint::Sensitive,
+            "idl_pda_authority" => FieldHint::PdaAuthority {
+                bump: str_name_value_arg(attr, &name, "bump")?,
+            },
+            "idl_string_enum" => string_enum_hint(attr, &name)?,
+            "idl_cid" => FieldHint::Cid(single_str_arg(attr, &name)?),
+            "idl_remaining" => FieldHint::Remaining {
+                index: int_name_value_arg(attr, &name, "index")?,
+            },
+            "idl_proof" => FieldHint::Proof,
+            "idl_decimal_string" => FieldHint::DecimalString,
+            "idl_program_id" => FieldHint::ProgramId,
+            "idl_paginated" => FieldHint::Paginated {
+                page_size: int_name_value_arg(attr, &name, "page_size")?,
+            },
+            "idl_ata" => ata_hint(attr, &name)?,
+            "idl_none_default" => {
+                FieldHint::NoneDefault(single_lit_arg(attr, &name)?)
+            }
+            "idl_bumps" => FieldHint::Bumps,
+            "idl_max_entries" => {
+                FieldHint::MaxEntries(single_int_arg(attr, &name)?)
+            }
+            "idl_authority_of" => {
+                FieldHint::AuthorityOf(single_str_arg(attr, &name)?)
+            }
+            "idl_uuid" => FieldHint::Uuid,
+            "idl_tx_message" => FieldHint::TxMessage {
+                version: int_name_value_arg(attr, &name, "version")?,
+            },
+            "idl_bps" => FieldHint::Bps,
+            "idl_close_destination" => FieldHint::CloseDestination,
+            "idl_secp256k1" => FieldHint::Secp256k1,
+            "idl_queue" => FieldHint::Queue(queue_mode_arg(attr, &name)?),
+            "idl_unlock_time" => FieldHint::UnlockTime,
+            "idl_eth_address" => FieldHint::EthAddress,
+            "idl_versioned_blob" => FieldHint::VersionedBlob {
+                prefix_bytes: int_name_value_arg(attr, &name, "prefix_bytes")?,
+            },
+            "idl_mint" => FieldHint::Mint {
+                decimals_field: str_name_value_arg(
+                    attr,
+                    &name,
+                    "decimals_field",
+                )?,
+            },
+            "idl_u8_percent" => FieldHint::U8Percent,
+            "idl_distribution" => FieldHint::Distribution,
+            "idl_keypair" => FieldHint::Keypair,
+            _ => return Ok(None),
+        };
+        Ok(Some(hint))
+    }
+}
+
+/// Parses `#[idl_account(signer, writable, init_if_needed)]` where each flag is optional.
+fn account_hint(attr: &Attribute, name: &str) -> ParseResult<FieldHint> {
+    let mut signer = false;
+    let mut writable = false;
+    let mut init_if_needed = false;
+    for flag in ident_list_args(attr, name)? {
+        match flag.to_string().as_str() {
+            "signer" => signer = true,
+            "writable" => writable = true,
+            "init_if_needed" => init_if_needed = true,
+            _ => {
+                return Err(ParseError::new(
+                    flag.span(),
+                    format!(
+                        "Unknown #[{}] flag `{}`, expected signer, writable or init_if_needed",
+                        name, flag
+                    ),
+                ))
+            }
+        }
+    }
+    Ok(FieldHint::Account {
+        signer,
+        writable,
+        init_if_needed,
+    })
+}
+
+/// Parses `#[idl_relation(name = "mint", account = "Mint")]` where both are required.
+fn relation_hint(attr: &Attribute, name: &str) -> ParseResult<FieldHint> {
+    let invalid = |span| {
+        ParseError::new(
+            span,
+            format!(
+                "#[{}] expects a name and account, i.e. #[{}(name = \"mint\", account = \"Mint\")]",
+                name, name
+            ),
+        )
+    };
+    let list = match attr.parse_meta()? {
+        Meta::List(list) => list,
+        _ => return Err(invalid(attr.span())),
+    };
+    let mut relation = None;
+    let mut account = None;
+    for nested in list.nested.iter() {
+        match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit),
+                ..
+            })) if path.is_ident("name") => relation = Some(lit.value()),
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit),
+                ..
+            })) if path.is_ident("account") => account = Some(lit.value()),
+            _ => return Err(invalid(nested.span())),
+        }
+    }
+    match (relation, account) {
+        (Some(name), Some(account)) => {
+            Ok(FieldHint::Relation { name, account })
+        }
+        _ => Err(invalid(attr.span())),
+    }
+}
+
+/// Parses `#[idl_ata(mint = "mint", owner = "owner")]` where both fields are required.
+fn ata_hint(attr: &Attribute, name: &str) -> ParseResult<FieldHint> {
+    let invalid = |span| {
+        ParseError::new(
+            span,
+            format!(
+                "#[{}] expects a mint and owner, i.e. #[{}(mint = \"mint\", owner = \"owner\")]",
+                name, name
+            ),
+        )
+    };
+    let list = match attr.parse_meta()? {
+        Meta::List(list) => list,
+        _ => return Err(invalid(attr.span())),
+    };
+    let mut mint = None;
+    let mut owner = None;
+    for nested in list.nested.iter() {
+        match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit),
+                ..
+            })) if path.is_ident("mint") => mint = Some(lit.value()),
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit),
+                ..
+            })) if path.is_ident("owner") => owner = Some(lit.value()),
+            _ => return Err(invalid(nested.span())),
+        }
+    }
+    match (mint, owner) {
+        (Some(mint), Some(owner)) => Ok(FieldHint::Ata { mint, owner }),
+        _ => Err(invalid(attr.span())),
+    }
+}
+
+/// Parses `#[idl_pda(program = "self", seeds = ["vault", authority])]` where the program is
+/// optional and each seed is either a string constant or the name of a field.
+/// String constants are kept quoted to tell them apart from fields.
+fn pda_hint(attr: &Attribute, name: &str) -> ParseResult<FieldHint> {
+    let invalid = |span| {
+        ParseError::new(
+            span,
+            format!(
+                "#[{}] expects seeds and an optional program, i.e. #[{}(program = \"self\", seeds = [\"vault\", authority])]",
+                name, name
+            ),
+        )
+    };
+    let args = attr
+        .parse_args_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        .map_err(|_| invalid(attr.span()))?;
+    let mut program = None;
+    let mut seeds = None;
+    for arg in args.iter() {
+        let (key, value) = match arg {
+            Expr::Assign(ExprAssign { left, right, .. }) => match left.as_ref()
+            {
+                Expr::Path(ExprPath { path, .. }) => (path.get_ident(), right),
+                _ => return Err(invalid(arg.span())),
+            },
+            _ => return Err(invalid(arg.span())),
+        };
+        match (key.map(Ident::to_string).as_deref(), value.as_ref()) {
+            (
+                Some("program"),
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit), ..
+                }),
+            ) => program = Some(lit.value()),
+            (Some("seeds"), Expr::Array(array)) => {
+                let parsed = array
+                    .elems
+                    .iter()
+                    .map(|seed| match seed {
+                        Expr::Lit(ExprLit {
+                            lit: Lit::Str(lit), ..
+                        }) => Ok(format!("{:?}", lit.value())),
+                        Expr::Path(ExprPath { path, .. }) => path
+                            .get_ident()
+                            .map(Ident::to_string)
+                            .ok_or_else(|| invalid(seed.span())),
+                        _ => Err(invalid(seed.span())),
+                    })
+                    .collect::<ParseResult<Vec<String>>>()?;
+                seeds = Some(parsed);
+            }
+            _ => return Err(invalid(arg.span())),
+        }
+    }
+    match seeds {
+        Some(seeds) => Ok(FieldHint::Pda { program, seeds }),
+        None => Err(invalid(attr.span())),
+    }
+}
+
+/// Parses `#[idl_string_enum(Status)]` which names exactly one enum.
+fn string_enum_hint(attr: &Attribute, name: &str) -> ParseResult<FieldHint> {
+    match ident_list_args(attr, name)?.as_slice() {
+        [enm] => Ok(FieldHint::StringEnum(enm.to_string())),
+        _ => Err(ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects the name of one enum, i.e. #[{}(Status)]",
+                name, name
+            ),
+        )),
+    }
+}
+
+/// Parses `#[idl_reinterpret(u64, big)]` where the endianness is either `big` or `little`.
+fn reinterpret_hint(attr: &Attribute, name: &str) -> ParseResult<FieldHint> {
+    match ident_list_args(attr, name)?.as_slice() {
+        [int, endian] if endian == "big" || endian == "little" => {
+            Ok(FieldHint::Reinterpret {
+                int: int.to_string(),
+                endian: endian.to_string(),
+            })
+        }
+        _ => Err(ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects an integer and big or little endianness, i.e. #[{}(u64, big)]",
+                name, name
+            ),
+        )),
+    }
+}
+
+/// Extracts the string literal of an attribute of the form `#[attr("value")]`.
+fn single_str_arg(attr: &Attribute, name: &str) -> ParseResult<String> {
+    let invalid = || {
+        ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects one string literal, i.e. #[{}(\"value\")]",
+                name, name
+            ),
+        )
+    };
+    match attr.parse_meta()? {
+        Meta::List(list) if list.nested.len() == 1 => {
+            match list.nested.first() {
+                Some(NestedMeta::Lit(Lit::Str(lit))) => Ok(lit.value()),
+                _ => Err(invalid()),
+            }
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Extracts the mode of `#[idl_queue("fifo")]` which is either `fifo` or `lifo`.
+fn queue_mode_arg(attr: &Attribute, name: &str) -> ParseResult<String> {
+    let mode = single_str_arg(attr, name)?;
+    match mode.as_str() {
+        "fifo" | "lifo" => Ok(mode),
+        _ => Err(ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects \"fifo\" or \"lifo\", but got \"{}\"",
+                name, mode
+            ),
+        )),
+    }
+}
+
+/// Extracts the integer literal of an attribute of the form `#[attr(1)]`.
+fn single_int_arg(attr: &Attribute, name: &str) -> ParseResult<usize> {
+    let invalid = || {
+        ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects one integer literal, i.e. #[{}(1)]",
+                name, name
+            ),
+        )
+    };
+    match attr.parse_meta()? {
+        Meta::List(list) if list.nested.len() == 1 => {
+            match list.nested.first() {
+                Some(NestedMeta::Lit(Lit::Int(lit))) => lit.base10_parse(),
+                _ => Err(invalid()),
+            }
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses `#[idl_range(min = 1, max = 100)]` where either bound may be omitted.
+fn range_hint(attr: &Attribute, name: &str) -> ParseResult<FieldHint> {
+    let invalid = |span| {
+        ParseError::new(
+            span,
+            format!(
+                "#[{}] expects integer bounds, i.e. #[{}(min = 1, max = 100)]",
+                name, name
+            ),
+        )
+    };
+    let list = match attr.parse_meta()? {
+        Meta::List(list) if !list.nested.is_empty() => list,
+        _ => return Err(invalid(attr.span())),
+    };
+    let mut min = None;
+    let mut max = None;
+    for nested in list.nested.iter() {
+        let (bound, lit) = match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Int(lit),
+                ..
+            })) => (path.get_ident().map(Ident::to_string), lit),
+            _ => return Err(invalid(nested.span())),
+        };
+        match bound.as_deref() {
+            Some("min") => min = Some(lit.base10_parse::<i128>()?),
+            Some("max") => max = Some(lit.base10_parse::<i128>()?),
+            _ => return Err(invalid(nested.span())),
+        }
+    }
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            return Err(ParseError::new(
+                attr.span(),
+                format!("#[{}] min {} is greater than max {}", name, min, max),
+            ));
+        }
+    }
+    Ok(FieldHint::Range { min, max })
+}
+
+/// Renders the literal of an attribute of the form `#[attr(0)]`, `#[attr(-1)]`,
+/// `#[attr(true)]` or `#[attr("value")]`, quoting strings.
+fn single_lit_arg(attr: &Attribute, name: &str) -> ParseResult<String> {
+    let invalid = || {
+        ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects one integer, bool or string literal, i.e. #[{}(0)]",
+                name, name
+            ),
+        )
+    };
+    match attr.parse_args::<Expr>().map_err(|_| invalid())? {
+        Expr::Lit(ExprLit { lit, .. }) => match lit {
+            Lit::Int(lit) => Ok(lit.base10_digits().to_string()),
+            Lit::Bool(lit) => Ok(lit.value.to_string()),
+            Lit::Str(lit) => Ok(format!("{:?}", lit.value())),
+            _ => Err(invalid()),
+        },
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match expr.as_ref() {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => Ok(format!("-{}", lit.base10_digits())),
+            _ => Err(invalid()),
+        },
+        _ => Err(invalid()),
+    }
+}
+
+/// Extracts the bytes of an attribute of the form `#[attr([0xDE, 0xAD])]`.
+fn byte_array_arg(attr: &Attribute, name: &str) -> ParseResult<Vec<u8>> {
+    let invalid = |span| {
+        ParseError::new(
+            span,
+            format!(
+                "#[{}] expects an array of bytes, i.e. #[{}([0xDE, 0xAD])]",
+                name, name
+            ),
+        )
+    };
+    let array = attr
+        .parse_args::<ExprArray>()
+        .map_err(|_| invalid(attr.span()))?;
+    array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(lit), ..
+            }) => lit.base10_parse::<u8>(),
+            _ => Err(invalid(elem.span())),
+        })
+        .collect()
+}
+
+/// Extracts the string literal of an attribute of the form `#[attr = "value"]`.
+fn name_value_str_arg(attr: &Attribute, name: &str) -> ParseResult<String> {
+    match attr.parse_meta()? {
+        Meta::NameValue(MetaNameValue {
+            lit: Lit::Str(lit), ..
+        }) => Ok(lit.value()),
+        _ => Err(ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects a string literal, i.e. #[{} = \"value\"]",
+                name, name
+            ),
+        )),
+    }
+}
+
+/// Extracts the integer of an attribute of the form `#[attr(key = 1)]`.
+fn int_name_value_arg(
+    attr: &Attribute,
+    name: &str,
+    key: &str,
+) -> ParseResult<usize> {
+    let invalid = || {
+        ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects an integer, i.e. #[{}({} = 1)]",
+                name, name, key
+            ),
+        )
+    };
+    match attr.parse_meta()? {
+        Meta::List(list) if list.nested.len() == 1 => {
+            match list.nested.first() {
+                Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Int(lit),
+                    ..
+                }))) if path.is_ident(key) => lit.base10_parse::<usize>(),
+                _ => Err(invalid()),
+            }
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Extracts the string literal of an attribute of the form `#[attr(key = "value")]`.
+fn str_name_value_arg(
+    attr: &Attribute,
+    name: &str,
+    key: &str,
+) -> ParseResult<String> {
+    let invalid = || {
+        ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects a string literal, i.e. #[{}({} = \"value\")]",
+                name, name, key
+            ),
+        )
+    };
+    match attr.parse_meta()? {
+        Meta::List(list) if list.nested.len() == 1 => {
+            match list.nested.first() {
+                Some(NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                }))) if path.is_ident(key) => Ok(lit.value()),
+                _ => Err(invalid()),
+            }
+        }
+        _ => Err(invalid()),
+    }
+}
+
+/// Extracts the string literals of an attribute of the form `#[attr("a", "b")]`.
+fn str_list_args(attr: &Attribute, name: &str) -> ParseResult<Vec<String>> {
+    let invalid = || {
+        ParseError::new(
+            attr.span(),
+            format!(
+                "#[{}] expects string literals, i.e. #[{}(\"a\", \"b\")]",
+                name, name
+            ),
+        )
+    };
+    match attr.parse_meta()? {
+        Meta::List(list) if !list.nested.is_empty() => list
+            .nested
+            .iter()
+            .map(|nested| match nested {
+                NestedMeta::Lit(Lit::Str(lit)) => Ok(lit.value()),
+                _ => Err(invalid()),
+            })
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
+
+/// Extracts the identifiers of an attribute of the form `#[attr(a, b)]`.
+fn ident_list_args(attr: &Attribute, name: &str) -> ParseResult<Vec<Ident>> {
+    let invalid = || {
+        ParseError::new(
+            attr.span(),
+            format!("#[{}] expects identifiers, i.e. #[{}(a, b)]", name, name),
+        )
+    };
+    match attr.parse_meta()? {
+        Meta::List(list) if !list.nested.is_empty() => list
+            .nested
+            .iter()
+            .map(|nested| match nested {
+                NestedMeta::Meta(Meta::Path(path)) => {
+                    path.get_ident().cloned().ok_or_else(invalid)
+                }
+                _ => Err(invalid()),
+            })
+            .collect(),
+        _ => Err(invalid()),
+    }
+}
@@ -6,8 +6,8 @@ use std::{
 
 use syn::{
     parse::{Parse, ParseStream},
-    Attribute, Error as ParseError, Field, Ident, ItemStruct,
-    Result as ParseResult,
+    Attribute, Error as ParseError, Field, Ident, ItemStruct, Lit, Meta,
+    MetaNameValue, Result as ParseResult,
 };
 
 use crate::{parsed_struct::struct_attr::StructAttrs, types::RustType};
@@ -19,6 +19,8 @@ pub struct StructField {
     pub ident: syn::Ident,
     pub rust_type: RustType,
     pub attrs: HashSet<StructFieldAttr>,
+    /// The lines of the `///` doc comments of the field
+    pub docs: Vec<String>,
 }
 
 impl Display for StructField {
@@ -44,6 +46,28 @@ impl StructField {
             }
         })
     }
+
+    /// Get the IDL type provided via `#[idl_type = "TypeName"]` if present
+    pub fn idl_type_str(&self) -> Option<&str> {
+        self.attrs.iter().find_map(|attr| {
+            if let StructFieldAttr::IdlTypeStr(ty) = attr {
+                Some(ty.as_str())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Get the name provided via `#[serde(rename = "name")]` if present
+    pub fn serde_rename(&self) -> Option<&str> {
+        self.attrs.iter().find_map(|attr| {
+            if let StructFieldAttr::SerdeRename(name) = attr {
+                Some(name.as_str())
+            } else {
+                None
+            }
+        })
+    }
 }
 
 impl TryFrom<&Field> for StructField {
@@ -51,7 +75,7 @@ impl TryFrom<&Field> for StructField {
 
     fn try_from(f: &Field) -> ParseResult<Self> {
         let ident = f.ident.as_ref().unwrap().clone();
-        let attrs = StructFieldAttrs::from(f.attrs.as_ref()).0;
+        let attrs = StructFieldAttrs::try_from(f.attrs.as_ref())?.0;
         let rust_type: RustType = match (&f.ty).try_into() {
             Ok(ty) => ty,
             Err(err) => {
@@ -59,14 +83,32 @@ impl TryFrom<&Field> for StructField {
             }
         };
 
+        let docs = doc_lines(&f.attrs);
+
         Ok(Self {
             ident,
             rust_type,
             attrs,
+            docs,
         })
     }
 }
 
+/// Extracts the lines of `///` doc comments, i.e. `#[doc = " line"]` attributes, in order.
+/// Each line is trimmed, but empty lines are kept.
+fn doc_lines(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("doc"))
+        .filter_map(|attr| match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue {
+                lit: Lit::Str(lit), ..
+            })) => Some(lit.value().trim().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 pub struct ParsedStruct {
     pub ident: Ident,
@@ -16,7 +16,7 @@ fn parse(input: TokenStream) -> ParsedStruct {
 }
 
 fn match_field(field: &StructField, field_ident: &str, type_ident: &str) {
-    assert_matches!(field, StructField { ident, rust_type, attrs } => {
+    assert_matches!(field, StructField { ident, rust_type, attrs, .. } => {
         assert_eq!(ident, field_ident);
         assert_eq!(rust_type.ident, type_ident);
         assert_eq!(attrs, &HashSet::new());
@@ -56,7 +56,7 @@ fn match_array_field_with_attrs(
     size: usize,
     field_attrs: &HashSet<StructFieldAttr>,
 ) {
-    assert_matches!(field, StructField { ident, rust_type, attrs } => {
+    assert_matches!(field, StructField { ident, rust_type, attrs, .. } => {
         assert_eq!(ident, field_ident);
         assert_eq!(rust_type.ident, "Array");
         assert_matches!(&rust_type.kind, TypeKind::Composite(Composite::Array(array_size), inner)  => {
@@ -366,7 +366,7 @@ mod account_with_idl_type_examples {
         match_field(&parsed.fields[0], "regular_field", "u32");
 
         // Field with idl_type attribute for enum
-        assert_matches!(&parsed.fields[1], StructField { ident, rust_type, attrs: _ } => {
+        assert_matches!(&parsed.fields[1], StructField { ident, rust_type, attrs: _, .. } => {
             assert_eq!(ident, "enum_as_byte");
             assert_eq!(rust_type.ident, "u8");
 
@@ -378,7 +378,7 @@ mod account_with_idl_type_examples {
         });
 
         // Field with idl_type attribute for wrapper type
-        assert_matches!(&parsed.fields[2], StructField { ident, rust_type, attrs: _ } => {
+        assert_matches!(&parsed.fields[2], StructField { ident, rust_type, attrs: _, .. } => {
             assert_eq!(ident, "wrapped_u64");
             assert_eq!(rust_type.ident, "CustomU64Wrapper");
 
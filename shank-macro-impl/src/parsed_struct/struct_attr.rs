@@ -201,6 +201,30 @@ impl TryFrom<&[Attribute]> for StructAttrs {
     }
 }
 
+// -----------------
+// #[idl(rename_all = "..")]
+// -----------------
+
+/// Extracts the case that the fields of a struct should be renamed to from its
+/// `#[idl(rename_all = "snake_case")]` attribute if present.
+pub fn rename_all_attr(attrs: &[Attribute]) -> ParseResult<Option<String>> {
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("idl")) {
+        if let Meta::List(MetaList { nested, .. }) = attr.parse_meta()? {
+            for arg in nested.iter() {
+                match arg {
+                    NestedMeta::Meta(Meta::NameValue(val))
+                        if val.path.is_ident("rename_all") =>
+                    {
+                        return extract_lit_str(&val.lit).map(Some);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    Ok(None)
+}
+
 fn param_args(
     meta: &Punctuated<NestedMeta, Comma>,
     span: &Span,
@@ -2,40 +2,83 @@ use std::collections::HashSet;
 use std::convert::TryFrom;
 
 use crate::types::RustType;
-use syn::{Attribute, Lit, Meta, NestedMeta};
+use syn::{
+    Attribute, Error as ParseError, Lit, Meta, MetaNameValue, NestedMeta,
+    Result as ParseResult,
+};
+
+use super::FieldHint;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum StructFieldAttr {
     Padding,
     IdlType(RustType),
+    /// `#[idl_type = "Vec<Pubkey>"]` whose value is parsed into the IDL type directly
+    IdlTypeStr(String),
+    Hint(FieldHint),
+    /// `#[serde(rename = "name")]` which controls the name on the wire
+    SerdeRename(String),
 }
 
 impl From<&StructFieldAttr> for String {
     fn from(attr: &StructFieldAttr) -> Self {
         match attr {
             StructFieldAttr::Padding => "padding".to_string(),
-            StructFieldAttr::IdlType(_) => "idl-type".to_string(),
+            StructFieldAttr::IdlType(_) | StructFieldAttr::IdlTypeStr(_) => {
+                "idl-type".to_string()
+            }
+            StructFieldAttr::Hint(_) => "hint".to_string(),
+            StructFieldAttr::SerdeRename(_) => "serde-rename".to_string(),
         }
     }
 }
 
 pub struct StructFieldAttrs(pub HashSet<StructFieldAttr>);
 
-impl From<&[Attribute]> for StructFieldAttrs {
-    fn from(attrs: &[Attribute]) -> Self {
-        Self(
-            attrs
-                .iter()
-                .filter_map(|attr| {
-                    if attr.path.is_ident("padding") {
-                        Some(StructFieldAttr::Padding)
-                    } else if attr.path.is_ident("idl_type") {
-                        if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
-                            for nested in meta_list.nested.iter() {
-                                match nested {
-                                    // Handle string literal format: #[idl_type("TypeName")]
-                                    NestedMeta::Lit(Lit::Str(lit_str)) => {
-                                        let type_str = lit_str.value();
+impl TryFrom<&[Attribute]> for StructFieldAttrs {
+    type Error = ParseError;
+
+    fn try_from(attrs: &[Attribute]) -> ParseResult<Self> {
+        let hints = attrs
+            .iter()
+            .filter_map(|attr| FieldHint::try_from_attr(attr).transpose())
+            .collect::<ParseResult<Vec<FieldHint>>>()?;
+
+        let mut field_attrs: HashSet<StructFieldAttr> = attrs
+            .iter()
+            .filter_map(|attr| {
+                if attr.path.is_ident("padding") {
+                    Some(StructFieldAttr::Padding)
+                } else if attr.path.is_ident("idl_type") {
+                    // Handle name value format: #[idl_type = "TypeName"]
+                    if let Ok(Meta::NameValue(MetaNameValue {
+                        lit: Lit::Str(lit_str),
+                        ..
+                    })) = attr.parse_meta()
+                    {
+                        return Some(StructFieldAttr::IdlTypeStr(
+                            lit_str.value(),
+                        ));
+                    }
+                    if let Ok(Meta::List(meta_list)) = attr.parse_meta() {
+                        for nested in meta_list.nested.iter() {
+                            match nested {
+                                // Handle string literal format: #[idl_type("TypeName")]
+                                NestedMeta::Lit(Lit::Str(lit_str)) => {
+                                    let type_str = lit_str.value();
+                                    if let Ok(rust_type) =
+                                        RustType::try_from(type_str.as_str())
+                                    {
+                                        return Some(StructFieldAttr::IdlType(
+                                            rust_type,
+                                        ));
+                                    }
+                                }
+                                // Handle direct type format: #[idl_type(TypeName)]
+                                NestedMeta::Meta(meta) => {
+                                    if let Some(ident) = meta.path().get_ident()
+                                    {
+                                        let type_str = ident.to_string();
                                         if let Ok(rust_type) =
                                             RustType::try_from(
                                                 type_str.as_str(),
@@ -47,59 +90,60 @@ impl From<&[Attribute]> for StructFieldAttrs {
                                                 ),
                                             );
                                         }
-                                    }
-                                    // Handle direct type format: #[idl_type(TypeName)]
-                                    NestedMeta::Meta(meta) => {
-                                        if let Some(ident) =
-                                            meta.path().get_ident()
-                                        {
-                                            let type_str = ident.to_string();
-                                            if let Ok(rust_type) =
-                                                RustType::try_from(
-                                                    type_str.as_str(),
-                                                )
-                                            {
-                                                return Some(
-                                                    StructFieldAttr::IdlType(
-                                                        rust_type,
-                                                    ),
-                                                );
-                                            }
-                                        } else {
-                                            // Handle path with segments (like std::string::String)
-                                            let path_str = meta
-                                                .path()
-                                                .segments
-                                                .iter()
-                                                .map(|seg| {
-                                                    seg.ident.to_string()
-                                                })
-                                                .collect::<Vec<_>>()
-                                                .join("::");
+                                    } else {
+                                        // Handle path with segments (like std::string::String)
+                                        let path_str = meta
+                                            .path()
+                                            .segments
+                                            .iter()
+                                            .map(|seg| seg.ident.to_string())
+                                            .collect::<Vec<_>>()
+                                            .join("::");
 
-                                            if let Ok(rust_type) =
-                                                RustType::try_from(
-                                                    path_str.as_str(),
-                                                )
-                                            {
-                                                return Some(
-                                                    StructFieldAttr::IdlType(
-                                                        rust_type,
-                                                    ),
-                                                );
-                                            }
+                                        if let Ok(rust_type) =
+                                            RustType::try_from(
+                                                path_str.as_str(),
+                                            )
+                                        {
+                                            return Some(
+                                                StructFieldAttr::IdlType(
+                                                    rust_type,
+                                                ),
+                                            );
                                         }
                                     }
-                                    _ => {}
                                 }
+                                _ => {}
                             }
                         }
-                        None
-                    } else {
-                        None
                     }
-                })
-                .collect(),
-        )
+                    None
+                } else if attr.path.is_ident("serde") {
+                    serde_rename(attr).map(StructFieldAttr::SerdeRename)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        field_attrs.extend(hints.into_iter().map(StructFieldAttr::Hint));
+
+        Ok(Self(field_attrs))
+    }
+}
+
+/// Extracts the name of `#[serde(rename = "name")]` ignoring any other serde options.
+fn serde_rename(attr: &Attribute) -> Option<String> {
+    match attr.parse_meta() {
+        Ok(Meta::List(list)) => {
+            list.nested.iter().find_map(|nested| match nested {
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(lit),
+                    ..
+                })) if path.is_ident("rename") => Some(lit.value()),
+                _ => None,
+            })
+        }
+        _ => None,
     }
 }
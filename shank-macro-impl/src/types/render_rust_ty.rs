@@ -1,4 +1,4 @@
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Literal, TokenStream};
 use quote::quote;
 
 use crate::types::{ParsedReference, TypeKind};
@@ -18,6 +18,10 @@ impl RustType {
                         quote!([#inner; #n])
                     }
                     Vec => todo!("Render Vec composite"),
+                    VecDeque => {
+                        let inner = inners[0].render();
+                        quote!(VecDeque<#inner>)
+                    }
                     Tuple => todo!("Render Tuple composite"),
                     Option => todo!("Render Option composite"),
                     HashMap => todo!("Render HashMap composite"),
@@ -28,6 +32,13 @@ impl RustType {
                         "Render Decimal composite with precision {}",
                         precision
                     ),
+                    Uint(bits) => {
+                        // only the bits are kept, the limbs are the u64s needed to hold them
+                        let limbs =
+                            Literal::usize_unsuffixed(bits.div_ceil(64));
+                        let bits = Literal::usize_unsuffixed(*bits);
+                        quote!(Uint<#bits, #limbs>)
+                    }
                     Custom(_) => todo!("Render Custom composite"),
                 }
             }
@@ -82,6 +93,10 @@ impl Primitive {
             Self::U128 => quote! { u128 },
             Self::I128 => quote! { i128 },
             Self::USize => quote! { usize },
+            Self::ISize => quote! { isize },
+            Self::F32 => quote! { f32 },
+            Self::F64 => quote! { f64 },
+            Self::Char => quote! { char },
             Self::Bool => quote! { bool },
         }
     }
@@ -104,7 +119,7 @@ impl Value {
 mod tests {
     use proc_macro2::{Ident, Span};
 
-    use crate::types::Primitive;
+    use crate::types::{Composite, Primitive};
 
     use super::*;
 
@@ -359,4 +374,40 @@ mod tests {
                 .unwrap(),
         );
     }
+
+    // -----------------
+    // Composites
+    // -----------------
+    #[test]
+    fn owned_vec_deque() {
+        assert_tokens_match(
+            RustType::owned(
+                "VecDeque",
+                TypeKind::Composite(
+                    Composite::VecDeque,
+                    vec![RustType::owned_primitive("u8", Primitive::U8)],
+                ),
+            )
+            .render(),
+            quote! { VecDeque<u8> },
+        );
+    }
+
+    #[test]
+    fn owned_uint() {
+        for (bits, expected) in [
+            (256, "Uint<256, 4>"),
+            (160, "Uint<160, 3>"),
+            (64, "Uint<64, 1>"),
+        ] {
+            assert_tokens_match(
+                RustType::owned(
+                    "Uint",
+                    TypeKind::Composite(Composite::Uint(bits), vec![]),
+                )
+                .render(),
+                expected.parse().unwrap(),
+            );
+        }
+    }
 }
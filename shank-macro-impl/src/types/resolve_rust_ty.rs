@@ -3,9 +3,9 @@ use std::{convert::TryFrom, ops::Deref};
 
 use quote::format_ident;
 use syn::{
-    spanned::Spanned, AngleBracketedGenericArguments, Expr, ExprLit,
-    GenericArgument, Ident, Lit, Path, PathArguments, PathSegment, Type,
-    TypeArray, TypePath, TypeTuple,
+    spanned::Spanned, AngleBracketedGenericArguments, Expr, ExprBlock, ExprLit,
+    ExprParen, GenericArgument, Ident, Lit, Path, PathArguments, PathSegment,
+    Stmt, Type, TypeArray, TypePath, TypeSlice, TypeTuple,
 };
 
 use super::{Composite, ParsedReference, Primitive, TypeKind, Value};
@@ -283,11 +283,30 @@ pub enum RustTypeContext {
     CustomItem,
 }
 
+/// Resolves the type from the last segment of the path, i.e. `Pubkey` for
+/// `solana_program::pubkey::Pubkey`.
+/// Custom values keep the full path so that external types can be told apart.
 fn ident_and_kind_from_path(path: &Path) -> (Ident, TypeKind) {
     let PathSegment {
         ident, arguments, ..
-    } = path.segments.first().unwrap();
-    (ident.clone(), ident_to_kind(ident, arguments))
+    } = path.segments.last().unwrap();
+    let kind = match ident_to_kind(ident, arguments) {
+        TypeKind::Value(Value::Custom(_)) if path.segments.len() > 1 => {
+            let segments = path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect::<Vec<String>>()
+                .join("::");
+            let full_path = match path.leading_colon {
+                Some(_) => format!("::{}", segments),
+                None => segments,
+            };
+            TypeKind::Value(Value::Custom(full_path))
+        }
+        kind => kind,
+    };
+    (ident.clone(), kind)
 }
 
 fn len_from_expr(expr: &Expr) -> ParseResult<usize> {
@@ -306,6 +325,15 @@ fn len_from_expr(expr: &Expr) -> ParseResult<usize> {
             };
             Ok(size)
         }
+        // braced const generic arguments, i.e. `Uint<{ 256 }, 4>`
+        Expr::Block(ExprBlock { block, .. }) => match block.stmts.as_slice() {
+            [Stmt::Expr(expr)] => len_from_expr(expr),
+            _ => Err(ParseError::new(
+                expr.span(),
+                "Expected a block with a single length expression",
+            )),
+        },
+        Expr::Paren(ExprParen { expr, .. }) => len_from_expr(expr),
         _ => Err(ParseError::new(
             expr.span(),
             "Expected a Lit(ExprLit(Int)) expression when extracting length",
@@ -322,7 +350,7 @@ pub fn resolve_rust_ty(
             let pr = ParsedReference::from(r);
             (r.elem.as_ref(), pr)
         }
-        Type::Array(_) | Type::Path(_) | Type::Tuple(_) => {
+        Type::Array(_) | Type::Path(_) | Type::Slice(_) | Type::Tuple(_) => {
             (ty, ParsedReference::Owned)
         }
         ty => {
@@ -362,11 +390,19 @@ pub fn resolve_rust_ty(
                 TypeKind::Composite(Composite::Array(len), vec![inner_ty]);
             (format_ident!("Array"), kind)
         }
+        // Slices serialize like a Vec of their element, i.e. `[u8]` inside
+        // `Cow<'_, [u8]>` ends up as `Vec<u8>`
+        Type::Slice(TypeSlice { elem, .. }) => {
+            let inner_ty =
+                resolve_rust_ty(elem, RustTypeContext::CollectionItem)?;
+            let kind = TypeKind::Composite(Composite::Vec, vec![inner_ty]);
+            (format_ident!("Vec"), kind)
+        }
         Type::Tuple(TypeTuple { elems, .. }) => {
-            if elems.len() < 2 {
+            if elems.is_empty() {
                 return Err(ParseError::new(
                     ty.span(),
-                    "A Tuple should have at least 2 type parameters",
+                    "A Tuple should have at least 1 type parameter",
                 ));
             }
 
@@ -433,6 +469,10 @@ fn ident_to_kind(ident: &Ident, arguments: &PathArguments) -> TypeKind {
                 "u128" => return TypeKind::Primitive(Primitive::U128),
                 "i128" => return TypeKind::Primitive(Primitive::I128),
                 "usize" => return TypeKind::Primitive(Primitive::USize),
+                "isize" => return TypeKind::Primitive(Primitive::ISize),
+                "f32" => return TypeKind::Primitive(Primitive::F32),
+                "f64" => return TypeKind::Primitive(Primitive::F64),
+                "char" => return TypeKind::Primitive(Primitive::Char),
                 "bool" => return TypeKind::Primitive(Primitive::Bool),
                 _ => {}
             };
@@ -454,15 +494,25 @@ fn ident_to_kind(ident: &Ident, arguments: &PathArguments) -> TypeKind {
             ..
         }) => {
             let pat = ident_str.as_str();
+            // Lifetimes don't affect the serialized shape, i.e. `Cow<'a, str>`
+            // is treated like `Cow<str>`
+            let args: Vec<&GenericArgument> = args
+                .iter()
+                .filter(|arg| !matches!(arg, GenericArgument::Lifetime(_)))
+                .collect();
             match args.len() {
+                // i.e. `Vec<>` or `Cow<'a>` which carry no type to resolve
+                0 => TypeKind::Unknown,
                 // -----------------
                 // Single Type Parameter
                 // -----------------
                 1 => match &args[0] {
                     GenericArgument::Type(ty) => match pat {
-                        "Vec" | "Option" | "HashSet" | "BTreeSet" => {
+                        "Vec" | "VecDeque" | "Option" | "HashSet"
+                        | "BTreeSet" => {
                             let composite = match pat {
                                 "Vec" => Composite::Vec,
+                                "VecDeque" => Composite::VecDeque,
                                 "Option" => Composite::Option,
                                 "HashSet" => Composite::HashSet,
                                 "BTreeSet" => Composite::BTreeSet,
@@ -558,6 +608,19 @@ fn ident_to_kind(ident: &Ident, arguments: &PathArguments) -> TypeKind {
                             inners,
                         )
                     }
+                    (
+                        GenericArgument::Const(bits), // const bit count
+                        GenericArgument::Const(_),    // const limb count
+                    ) if ident_str == "Uint" => {
+                        // bit counts given via constants can't be resolved here
+                        match len_from_expr(bits) {
+                            Ok(bits) => TypeKind::Composite(
+                                Composite::Uint(bits),
+                                vec![],
+                            ),
+                            Err(_) => TypeKind::Unknown,
+                        }
+                    }
                     _ => TypeKind::Unknown,
                 },
                 _ => {
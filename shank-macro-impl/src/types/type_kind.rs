@@ -138,6 +138,7 @@ impl TypeKind {
             TypeKind::Primitive(_) => None,
             TypeKind::Value(_) => None,
             TypeKind::Composite(Composite::Vec, inners)
+            | TypeKind::Composite(Composite::VecDeque, inners)
             | TypeKind::Composite(Composite::Array(_), inners)
             | TypeKind::Composite(Composite::HashSet, inners)
             | TypeKind::Composite(Composite::BTreeSet, inners) => {
@@ -214,6 +215,10 @@ pub enum Primitive {
     U128,
     I128,
     USize,
+    ISize,
+    F32,
+    F64,
+    Char,
     Bool,
 }
 
@@ -231,6 +236,10 @@ impl Debug for Primitive {
             Primitive::U128 => "Primitive::U128",
             Primitive::I128 => "Primitive::I128",
             Primitive::USize => "Primitive::Usize",
+            Primitive::ISize => "Primitive::Isize",
+            Primitive::F32 => "Primitive::F32",
+            Primitive::F64 => "Primitive::F64",
+            Primitive::Char => "Primitive::Char",
             Primitive::Bool => "Primitive::Bool",
         };
         write!(f, "{}", ty)
@@ -251,6 +260,10 @@ impl Display for Primitive {
             Primitive::U128 => "u128",
             Primitive::I128 => "i128",
             Primitive::USize => "usize",
+            Primitive::ISize => "isize",
+            Primitive::F32 => "f32",
+            Primitive::F64 => "f64",
+            Primitive::Char => "char",
             Primitive::Bool => "bool",
         };
         write!(f, "{}", ty)
@@ -325,6 +338,8 @@ impl Hash for Value {
 #[derive(Clone, PartialEq, Eq)]
 pub enum Composite {
     Vec,
+    /// `VecDeque<T>` which is serialized the same as `Vec<T>`
+    VecDeque,
     Array(usize),
     Tuple,
     Option,
@@ -333,6 +348,8 @@ pub enum Composite {
     HashSet,
     BTreeSet,
     Decimal(usize),
+    /// `ruint::Uint<BITS, LIMBS>` big integer with the provided number of bits
+    Uint(usize),
     Custom(String),
 }
 
@@ -340,6 +357,7 @@ impl Debug for Composite {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Composite::Vec => write!(f, "Composite::Vec"),
+            Composite::VecDeque => write!(f, "Composite::VecDeque"),
             Composite::Array(size) => write!(f, "Composite::Array({})", size),
             Composite::Tuple => write!(f, "Composite::Tuple"),
             Composite::Option => write!(f, "Composite::Option"),
@@ -348,6 +366,7 @@ impl Debug for Composite {
             Composite::HashSet => write!(f, "Composite::HashSet"),
             Composite::BTreeSet => write!(f, "Composite::BTreeSet"),
             Composite::Decimal(p) => write!(f, "Composite::Decimal({})", p),
+            Composite::Uint(bits) => write!(f, "Composite::Uint({})", bits),
             Composite::Custom(name) => {
                 write!(f, "Composite::Custom(\"{}\")", name)
             }
@@ -368,6 +387,9 @@ impl Hash for Composite {
             Composite::Decimal(p) => {
                 p.hash(state);
             }
+            Composite::Uint(bits) => {
+                bits.hash(state);
+            }
             Composite::Custom(s) => {
                 s.hash(state);
             }
@@ -28,10 +28,11 @@ mod instruction;
 /// 2. Fields storing enum values as primitives (like `u8`) that should be recognized as enums
 /// 3. Fields with complex types that need simpler representations in the IDL
 ///
-/// The attribute supports two formats:
+/// The attribute supports three formats:
 ///
 /// 1. **String literal format**: `#[idl_type("TypeName")]`
 /// 2. **Direct type format**: `#[idl_type(TypeName)]`
+/// 3. **Name value format**: `#[idl_type = "TypeName"]` which takes precedence over the others
 ///
 /// ```
 /// use shank::ShankAccount;
@@ -48,10 +49,95 @@ mod instruction;
 /// }
 /// ```
 ///
+/// ## `#[idl(rename_all = "...")]` attribute
+///
+/// Placed on the struct itself, this attribute controls how the names of its fields are
+/// converted in the IDL. Supported are `"camelCase"` (the default), `"snake_case"` and
+/// `"preserve"` which keeps the field identifiers untouched.
+///
+/// ```
+/// use shank::ShankAccount;
+///
+/// #[derive(ShankAccount)]
+/// #[idl(rename_all = "snake_case")]
+/// pub struct MyAccount {
+///     // Named `update_authority` in the IDL instead of `updateAuthority`
+///     pub update_authority: Pubkey,
+/// }
+/// ```
+///
 /// ## `#[padding]` attribute
 ///
 /// Indicates that a field is used for padding and should be marked as such in the IDL.
 ///
+/// ## Field hint attributes
+///
+/// The following attributes don't change the type of a field, but add `@` docs to it in the IDL
+/// which tell clients how to interpret its data.
+///
+/// - `#[idl_reserved]`: the array is reserved for future expansion (`@reserved`)
+/// - `#[idl_encoding("protobuf")]`: the bytes are encoded with the given format (`@encoding protobuf`)
+/// - `#[idl_token_labels("SOL", "USDC")]`: labels each index of a balances array (`@tokenLabels SOL,USDC`)
+/// - `#[idl_stream]`: the data is large and should be fetched separately, i.e. via account data slicing (`@stream`)
+/// - `#[idl_owner("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")]`: the program expected to own the account of the public key (`@owner=Tokenkeg..`)
+/// - `#[idl_fixed_string]`: the byte array holds null-padded text (`@fixedString len=16`)
+/// - `#[idl_utf16]`: the `Vec<u16>` holds UTF-16 encoded text (`@utf16`)
+/// - `#[idl_account(signer, writable, init_if_needed)]`: how the account of the public key is passed to instructions (`@signer`, `@writable`, `@initIfNeeded`)
+/// - `#[idl_default(0)]`: the default value of the field as integer, bool or string literal (`@default=0`)
+/// - `#[idl_unique]`: the items of the vec or set must be unique (`@unique`)
+/// - `#[idl_version]`: the `u8` or `u16` holds the version of the account layout (`@version`)
+/// - `#[idl_histogram]`: the vec holds `(bucket, count)` pairs (`@histogram`)
+/// - `#[idl_optional_update]`: `None` leaves the current value unchanged (`@optionalUpdate`)
+/// - `#[idl_range(min = 1, max = 100)]`: the integer is constrained to the inclusive range (`@range min=1 max=100`)
+/// - `#[idl_magic([0xDE, 0xAD, 0xBE, 0xEF])]`: the `[u8; 4]` holds a magic number (`@magic DEADBEEF`)
+/// - `#[idl_membership]`: the map to `bool` is used as a set of its keys (`@membership`)
+/// - `#[idl_mime("image/png")]`: the MIME type of the bytes (`@mime image/png`)
+/// - `#[idl_leaf]`: the 32 byte array is a leaf hash of a compressed account tree (`@leaf`)
+/// - `#[idl_deprecated("use new_field")]`: the field should no longer be used (`@deprecated use new_field`)
+/// - `#[idl_len_from = "num_signers"]`: the number of items is held by another field (`@lenFrom=num_signers`, plus `@pubkeyVec` for a `Vec<Pubkey>`)
+/// - `#[idl_pubkey_keys]`: the string keys of the map are base58 encoded public keys (`@pubkeyKeys`)
+/// - `#[idl_system_program_default]`: the public key defaults to the system program (`@defaultsTo=11111111111111111111111111111111`)
+/// - `#[idl_units("lamports")]`: the unit of the integer (`@units lamports`)
+/// - `#[idl_merkle_root(depth = 14)]`: the 32 byte array is the root of a merkle tree (`@merkleRoot depth=14`)
+/// - `#[idl_envelope("eip712")]`: the bytes are a signed message wrapped in the typed-data envelope (`@envelope eip712`)
+/// - `#[idl_datetime]`: the `i64` or `u64` is a unix timestamp in seconds (`@datetime unixSeconds`)
+/// - `#[idl_sorted]`: the items of the vec are kept in sorted order (`@sorted`)
+/// - `#[idl_relation(name = "mint", account = "Mint")]`: the public key points to an account of the given type (`@relation mint->Mint`)
+/// - `#[idl_byte_flag]`: the `[u8; 1]` is interpreted as a boolean (`@byteFlag`)
+/// - `#[idl_computed]`: the field is derived off-chain and must not be written by clients (`@computed`)
+/// - `#[idl_compact_u16]`: the length of the vec is prefixed as variable length compact-u16 (`@compactU16`)
+/// - `#[idl_tristate]`: the `Option<bool>` represents true, false or unset (`@tristate`)
+/// - `#[idl_pda(program = "self", seeds = ["vault", authority])]`: the public key is a PDA derived from string constants and fields (`@pda seeds=["vault", authority]`, plus `@pdaProgram=self` when the program is provided)
+/// - `#[idl_reinterpret(u64, big)]`: the byte array holds an integer of the given endianness (`@reinterpret u64 big`)
+/// - `#[idl_role_table]`: the map assigns public keys to integer role ids (`@roleTable`)
+/// - `#[idl_sensitive]`: the field holds a secret that tooling should redact (`@sensitive`)
+/// - `#[idl_pda_authority(bump = "vault_bump")]`: the PDA authority whose bump is held by the named field (`@pdaAuthority bump=vault_bump`)
+/// - `#[idl_string_enum(Status)]`: the `u8` holds the variant of the `#[repr(u8)]` enum `Status`, its variants listed in discriminant order (`@stringEnum Active,Closed`)
+/// - `#[idl_cid("dag-pb")]`: the bytes hold an IPFS CID of the given codec (`@cid codec=dag-pb`)
+/// - `#[idl_remaining(index = 0)]`: the public key is passed as remaining account at the index (`@remaining index=0`)
+/// - `#[idl_proof]`: the vec of hashes is a merkle proof (`@proof`)
+/// - `#[idl_decimal_string]`: clients should store the `u128` or `i128` as decimal string (`@decimalString`)
+/// - `#[idl_program_id]`: the public key is the id of the program itself (`@programId`)
+/// - `#[idl_paginated(page_size = 50)]`: clients should fetch the vec in pages of the given size (`@paginated pageSize=50`)
+/// - `#[idl_ata(mint = "mint", owner = "owner")]`: the public key is the associated token account of the owner and mint fields (`@ata mint=mint owner=owner`)
+/// - `#[idl_none_default(100)]`: `None` of the optional integer implies the provided value (`@noneDefault=100`)
+/// - `#[idl_bumps]`: the `Vec<u8>` holds PDA bump seeds (`@bumps`)
+/// - `#[idl_max_entries(100)]`: the map or set holds at most the provided number of entries (`@maxEntries=100`)
+/// - `#[idl_authority_of("vault")]`: the public key must sign as authority of the provided field (`@authorityOf vault`)
+/// - `#[idl_uuid]`: clients should format the `[u8; 16]` as a UUID string (`@uuid`)
+/// - `#[idl_tx_message(version = 0)]`: the `Vec<u8>` is a serialized transaction message of the version (`@txMessage version=0`)
+/// - `#[idl_bps]`: the `u16` or `u32` holds basis points which clients divide by 10000 (`@bps`)
+/// - `#[idl_close_destination]`: the public key receives the lamports when the account is closed (`@closeDestination`)
+/// - `#[idl_secp256k1]`: the `[u8; 33]` is a compressed secp256k1 public key (`@secp256k1`)
+/// - `#[idl_queue("fifo")]`: the vec is a queue with `fifo` or `lifo` semantics (`@queue fifo`)
+/// - `#[idl_unlock_time]`: the `i64` is the unix timestamp at which a lockup ends (`@unlockTime`)
+/// - `#[idl_eth_address]`: clients should render the `[u8; 20]` Ethereum address as `0x` prefixed hex (`@ethAddress`, `@encoding hex-0x`)
+/// - `#[idl_versioned_blob(prefix_bytes = 1)]`: the leading bytes of the `Vec<u8>` hold the schema version of the rest (`@versionedBlob prefixBytes=1`)
+/// - `#[idl_mint(decimals_field = "decimals")]`: the public key is a mint whose decimals are stored in the provided field (`@mint decimalsField=decimals`)
+/// - `#[idl_u8_percent]`: the `u8` is a percentage which clients divide by 255 (`@u8Percent`)
+/// - `#[idl_distribution]`: the `Vec<(Pubkey, _)>` distributes shares to the public keys (`@distribution`)
+/// - `#[idl_keypair]`: the `[u8; 64]` holds a keypair, i.e. of an ed25519 or x25519 key (`@keypair`)
+///
 /// # Example
 ///
 /// ```
@@ -110,7 +196,77 @@ mod instruction;
 ///
 /// The fields of a _ShankAccount_ struct can reference other types as long as they are annotated
 /// with `ShankType`, `BorshSerialize` or `BorshDeserialize`.
-#[proc_macro_derive(ShankAccount, attributes(padding, seeds, idl_type))]
+#[proc_macro_derive(
+    ShankAccount,
+    attributes(
+        padding,
+        seeds,
+        idl,
+        idl_type,
+        idl_reserved,
+        idl_encoding,
+        idl_token_labels,
+        idl_stream,
+        idl_owner,
+        idl_fixed_string,
+        idl_utf16,
+        idl_account,
+        idl_default,
+        idl_unique,
+        idl_version,
+        idl_histogram,
+        idl_optional_update,
+        idl_range,
+        idl_magic,
+        idl_membership,
+        idl_mime,
+        idl_leaf,
+        idl_deprecated,
+        idl_len_from,
+        idl_pubkey_keys,
+        idl_system_program_default,
+        idl_units,
+        idl_merkle_root,
+        idl_envelope,
+        idl_datetime,
+        idl_sorted,
+        idl_relation,
+        idl_byte_flag,
+        idl_computed,
+        idl_compact_u16,
+        idl_tristate,
+        idl_pda,
+        idl_reinterpret,
+        idl_role_table,
+        idl_sensitive,
+        idl_pda_authority,
+        idl_string_enum,
+        idl_cid,
+        idl_remaining,
+        idl_proof,
+        idl_decimal_string,
+        idl_program_id,
+        idl_paginated,
+        idl_ata,
+        idl_none_default,
+        idl_bumps,
+        idl_max_entries,
+        idl_authority_of,
+        idl_uuid,
+        idl_tx_message,
+        idl_bps,
+        idl_close_destination,
+        idl_secp256k1,
+        idl_queue,
+        idl_unlock_time,
+        idl_eth_address,
+        idl_versioned_blob,
+        idl_mint,
+        idl_u8_percent,
+        idl_distribution,
+        idl_keypair
+    )
+)]
 pub fn shank_account(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     derive_account(input)
@@ -354,7 +510,77 @@ pub fn shank_context(input: TokenStream) -> TokenStream {
 ///
 /// The fields of a _ShankType_ struct or enum can reference other types as long as they are annotated
 /// with `ShankType`, `BorshSerialize` or `BorshDeserialize`.
-#[proc_macro_derive(ShankType)]
+///
+/// The fields of a _ShankType_ struct support the same field hint attributes as _ShankAccount_,
+/// i.e. `#[idl_optional_update]` for instruction args.
+#[proc_macro_derive(
+    ShankType,
+    attributes(
+        idl,
+        idl_reserved,
+        idl_encoding,
+        idl_token_labels,
+        idl_stream,
+        idl_owner,
+        idl_fixed_string,
+        idl_utf16,
+        idl_account,
+        idl_default,
+        idl_unique,
+        idl_version,
+        idl_histogram,
+        idl_optional_update,
+        idl_range,
+        idl_magic,
+        idl_membership,
+        idl_mime,
+        idl_leaf,
+        idl_deprecated,
+        idl_len_from,
+        idl_pubkey_keys,
+        idl_system_program_default,
+        idl_units,
+        idl_merkle_root,
+        idl_envelope,
+        idl_datetime,
+        idl_sorted,
+        idl_relation,
+        idl_byte_flag,
+        idl_computed,
+        idl_compact_u16,
+        idl_tristate,
+        idl_pda,
+        idl_reinterpret,
+        idl_role_table,
+        idl_sensitive,
+        idl_pda_authority,
+        idl_string_enum,
+        idl_cid,
+        idl_remaining,
+        idl_proof,
+        idl_decimal_string,
+        idl_program_id,
+        idl_paginated,
+        idl_ata,
+        idl_none_default,
+        idl_bumps,
+        idl_max_entries,
+        idl_authority_of,
+        idl_uuid,
+        idl_tx_message,
+        idl_bps,
+        idl_close_destination,
+        idl_secp256k1,
+        idl_queue,
+        idl_unlock_time,
+        idl_eth_address,
+        idl_versioned_blob,
+        idl_mint,
+        idl_u8_percent,
+        idl_distribution,
+        idl_keypair
+    )
+)]
 pub fn shank_type(_input: TokenStream) -> TokenStream {
     // returns the token stream that was passed in (the macro is only an annotation for shank-idl
     // to export the type in the program's IDL)